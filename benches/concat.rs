@@ -0,0 +1,68 @@
+use array_manipulation::ArrayAdd;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// `concat` lowers to two `memcpy()`s of the inputs into a stack temporary and
+// a third one into the return slot. Writing the halves straight into a
+// `MaybeUninit` (either by `write` or `copy_nonoverlapping`) emits the exact
+// same code, as LLVM can't elide the copies of by-value arguments either way.
+fn concat(c: &mut Criterion) {
+    let mut group = c.benchmark_group("concat [u8; 4096]");
+    let (a, b) = ([1u8; 4096], [2u8; 4096]);
+
+    group.bench_function("ArrayAdd::concat", |bench| {
+        bench.iter(|| black_box(black_box(a).concat(black_box(b))))
+    });
+    group.bench_function("Vec::extend_from_slice", |bench| {
+        bench.iter(|| {
+            let mut result = Vec::with_capacity(8192);
+            result.extend_from_slice(black_box(&a));
+            result.extend_from_slice(black_box(&b));
+            black_box(result)
+        })
+    });
+    group.finish();
+}
+
+// `concat` with a single-element array already goes through the same
+// `Contiguous` transmute as `push_back`, and both lower to identical code (one
+// `memcpy()` of Self plus a store of the element), so no `L == 1` special case
+// is needed in `concat`.
+fn concat_single(c: &mut Criterion) {
+    let mut group = c.benchmark_group("append to [u8; 4096]");
+    let (a, x) = ([1u8; 4096], 2u8);
+
+    group.bench_function("ArrayAdd::concat", |bench| {
+        bench.iter(|| black_box(black_box(a).concat([black_box(x)])))
+    });
+    group.bench_function("ArrayAdd::push_back", |bench| {
+        bench.iter(|| black_box(black_box(a).push_back(black_box(x))))
+    });
+    group.finish();
+}
+
+// Every `push_back` of a chain moves the whole growing array into a new one,
+// while `append_many` copies Self and the elements just once.
+fn append_chain(c: &mut Criterion) {
+    let mut group = c.benchmark_group("append 3 elements to [u8; 4096]");
+    let (a, x, y, z) = ([1u8; 4096], 2u8, 3u8, 4u8);
+
+    group.bench_function("ArrayAdd::push_back chain", |bench| {
+        bench.iter(|| {
+            black_box(
+                black_box(a)
+                    .push_back(black_box(x))
+                    .push_back(black_box(y))
+                    .push_back(black_box(z)),
+            )
+        })
+    });
+    group.bench_function("ArrayAdd::append_many", |bench| {
+        bench.iter(|| {
+            black_box(black_box(a).append_many([black_box(x), black_box(y), black_box(z)]))
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, concat, concat_single, append_chain);
+criterion_main!(benches);
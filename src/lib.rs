@@ -5,12 +5,28 @@
 #![feature(const_refs_to_cell)]
 #![feature(const_ptr_read)]
 #![feature(specialization)]
+#![feature(const_destruct)]
+#![feature(const_cmp)]
+#![feature(const_convert)]
+#![feature(const_ops)]
+#![feature(const_drop_in_place)]
 #![cfg_attr(not(test), no_std)]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
 use core::{
-    mem::{forget, ManuallyDrop},
-    ptr::{drop_in_place, read},
+    cmp::Ordering,
+    marker::Destruct,
+    mem::{forget, replace, size_of, ManuallyDrop, MaybeUninit},
+    ops::{BitAnd, BitOr, BitXor},
+    ptr::{
+        copy, copy_nonoverlapping, drop_in_place, read, slice_from_raw_parts,
+        slice_from_raw_parts_mut, swap,
+    },
 };
 
 /// Holds the append methods.
@@ -19,31 +35,91 @@ use core::{
 /// becomes complete.
 // TODO implement append_at & concat_at when const exprs become usable enough
 pub trait ArrayAdd<T, const N: usize>: Sized {
-    /// Inserts an element at the end of Self. Use concat for >1 elements.
+    /// Inserts an element at the end of Self. Use append_array for >1 elements.
     /// # Examples
     /// ```
     /// use array_manipulation::ArrayAdd;
     ///
     /// let array: [u8; 4] = [1, 2, 3, 4];
     /// let expected = [1, 2, 3, 4, 5];
-    /// let result = array.append(5);
+    /// let result = array.push_back(5);
     /// assert_eq!(expected, result);
     /// ```
-    fn append(self, e: T) -> [T; N + 1];
+    fn push_back(self, e: T) -> [T; N + 1];
 
-    /// Inserts an element at the start of Self. Use concat for >1 elements.
+    /// Inserts an element at the start of Self. Use prepend_array for >1 elements.
     /// # Examples
     /// ```
     /// use array_manipulation::ArrayAdd;
     ///
     /// let array: [u8; 4] = [1, 2, 3, 4];
     /// let expected = [0, 1, 2, 3, 4];
-    /// let result = array.append_back(0);
+    /// let result = array.push_front(0);
+    /// assert_eq!(expected, result);
+    /// ```
+    fn push_front(self, e: T) -> [T; N + 1];
+
+    /// Inserts an element at the end of Self.
+    #[deprecated(note = "renamed to `push_back`")]
+    fn append(self, e: T) -> [T; N + 1] {
+        self.push_back(e)
+    }
+
+    /// Inserts an element at the start of Self, despite its name.
+    #[deprecated(note = "renamed to `push_front`, as it doesn't insert at the back")]
+    fn append_back(self, e: T) -> [T; N + 1] {
+        self.push_front(e)
+    }
+
+    /// Takes an array of L elements and appends it at the end of Self.
+    /// Same as concat.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayAdd;
+    ///
+    /// let array: [u8; 4] = [1, 2, 3, 4];
+    /// let expected = [1, 2, 3, 4, 5, 6, 7];
+    /// let result = array.append_array([5, 6, 7]);
+    /// assert_eq!(expected, result);
+    /// ```
+    fn append_array<const L: usize>(self, array: [T; L]) -> [T; N + L] {
+        self.concat(array)
+    }
+
+    /// Takes an array of L elements and inserts it at the start of Self.
+    /// Same as concat_back.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayAdd;
+    ///
+    /// let array: [u8; 4] = [1, 2, 3, 4];
+    /// let expected = [254, 255, 0, 1, 2, 3, 4];
+    /// let result = array.prepend_array([254, 255, 0]);
     /// assert_eq!(expected, result);
     /// ```
-    fn append_back(self, e: T) -> [T; N + 1];
+    fn prepend_array<const L: usize>(self, array: [T; L]) -> [T; N + L] {
+        self.concat_back(array)
+    }
+
+    /// Appends every element of elems at the end of Self at once.
+    /// Same as concat, but prefer it over chaining push_back, as every
+    /// step of a chain copies the whole growing array again.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayAdd;
+    ///
+    /// let array: [u8; 2] = [1, 2];
+    /// let chained = array.push_back(3).push_back(4).push_back(5);
+    /// let result = array.append_many([3, 4, 5]);
+    /// assert_eq!(chained, result);
+    /// ```
+    fn append_many<const M: usize>(self, elems: [T; M]) -> [T; N + M];
 
     /// Takes an array of L elements and appends it at the end of Self.
+    /// Note that `[T; N] + [T; L]` can't be offered as sugar for this,
+    /// as both `core::ops::Add` and arrays are foreign to this crate.
+    /// The elements are moved bitwise and nothing is dropped, so arrays
+    /// of `MaybeUninit` can be joined even if partially initialized.
     /// # Examples
     /// ```
     /// use array_manipulation::ArrayAdd;
@@ -55,7 +131,7 @@ pub trait ArrayAdd<T, const N: usize>: Sized {
     /// ```
     fn concat<const L: usize>(self, array: [T; L]) -> [T; N + L];
 
-    /// Takes an array of L elements and appends it at the end of Self.
+    /// Takes an array of L elements and inserts it at the start of Self.
     /// # Examples
     /// ```
     /// use array_manipulation::ArrayAdd;
@@ -66,6 +142,259 @@ pub trait ArrayAdd<T, const N: usize>: Sized {
     /// assert_eq!(expected, result);
     /// ```
     fn concat_back<const L: usize>(self, array: [T; L]) -> [T; N + L];
+
+    /// Copies the elements of Self and then the ones of another array
+    /// into a new one, without taking ownership of either. Requires T
+    /// to be Copy, use concat for moving non-Copy elements.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayAdd;
+    ///
+    /// let array: [u8; 4] = [1, 2, 3, 4];
+    /// let expected = [1, 2, 3, 4, 5, 6, 7];
+    /// let result = array.concat_ref(&[5, 6, 7]);
+    /// assert_eq!(expected, result);
+    /// assert_eq!([1, 2, 3, 4], array);
+    /// ```
+    fn concat_ref<const L: usize>(&self, array: &[T; L]) -> [T; N + L]
+    where
+        T: Copy;
+
+    /// Copies the elements of a `'static` array, like a `const` one,
+    /// at the end of Self. Requires T to be Copy, use concat for
+    /// arrays owned by the caller.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayAdd;
+    ///
+    /// const MAGIC: [u8; 2] = [0xca, 0xfe];
+    /// let array: [u8; 2] = [1, 2];
+    /// let expected = [1, 2, 0xca, 0xfe];
+    /// let result = array.concat_const(&MAGIC);
+    /// assert_eq!(expected, result);
+    /// ```
+    fn concat_const<const L: usize>(self, array: &'static [T; L]) -> [T; N + L]
+    where
+        T: Copy;
+
+    /// Takes an array of L elements and inserts it reversed at the start
+    /// of Self. Unlike concat_back, which keeps the order of array, the
+    /// last element of array becomes the first one of the result.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayAdd;
+    ///
+    /// let array: [u8; 4] = [1, 2, 3, 4];
+    /// let expected = [0, 255, 254, 1, 2, 3, 4];
+    /// let result = array.concat_back_rev([254, 255, 0]);
+    /// assert_eq!(expected, result);
+    /// ```
+    fn concat_back_rev<const L: usize>(self, array: [T; L]) -> [T; N + L];
+
+    /// Takes an array of L elements and appends it reversed at the end
+    /// of Self, the counterpart of concat_back_rev, saving a separate
+    /// reverse before concat.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayAdd;
+    ///
+    /// let array: [u8; 3] = [1, 2, 3];
+    /// let expected = [1, 2, 3, 3, 2, 1];
+    /// let result = array.concat_reversed(array);
+    /// assert_eq!(expected, result);
+    /// ```
+    fn concat_reversed<const L: usize>(self, array: [T; L]) -> [T; N + L];
+
+    /// Same as concat, but initializes a caller-provided buffer instead
+    /// of returning the result by value, avoiding a big temporary on the
+    /// stack. The buffer can live anywhere, like on the heap.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayAdd;
+    /// use core::mem::MaybeUninit;
+    ///
+    /// let array: [u8; 4] = [1, 2, 3, 4];
+    /// let mut buffer = MaybeUninit::uninit();
+    /// let result = array.concat_into([5, 6, 7], &mut buffer);
+    /// assert_eq!(&[1, 2, 3, 4, 5, 6, 7], result);
+    /// ```
+    fn concat_into<const L: usize>(
+        self,
+        array: [T; L],
+        out: &mut MaybeUninit<[T; N + L]>,
+    ) -> &mut [T; N + L];
+
+    /// Weaves Self and another array of the same length into a single
+    /// array, alternating between their elements starting with Self's.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayAdd;
+    ///
+    /// let left: [u8; 3] = [1, 3, 5];
+    /// let right: [u8; 3] = [2, 4, 6];
+    /// let expected = [1, 2, 3, 4, 5, 6];
+    /// let result = left.interleave(right);
+    /// assert_eq!(expected, result);
+    /// ```
+    fn interleave(self, other: [T; N]) -> [T; 2 * N];
+
+    /// Same as `concat`, but converts every element of array into T
+    /// while moving it, avoiding a separate pass to map it first.
+    /// If a conversion panics, the elements already moved into the
+    /// result and the ones not yet converted are dropped.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayAdd;
+    ///
+    /// let array: [i16; 2] = [1000, 2000];
+    /// let expected = [1000, 2000, -1, 1];
+    /// let result = array.concat_map([-1i8, 1]);
+    /// assert_eq!(expected, result);
+    /// ```
+    fn concat_map<U: ~const Into<T>, const L: usize>(self, array: [U; L]) -> [T; N + L];
+
+    /// Passes Self through f, so several steps of a build can be
+    /// chained instead of nested, even the ones needing a binding.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::{ArrayAdd, ArrayRemove};
+    ///
+    /// let header: [u8; 2] = [0xca, 0xfe];
+    /// let result = header
+    ///     .pipe(|a| a.concat([1, 2, 3]))
+    ///     .pipe(|a| a.push_back(a.iter().fold(0, |acc, e| acc ^ e)))
+    ///     .pipe(|a| a.truncate_start::<1>());
+    /// assert_eq!([0xfe, 1, 2, 3, 0x34], result);
+    /// ```
+    fn pipe<R, F: ~const FnOnce(Self) -> R + ~const Destruct>(self, f: F) -> R;
+
+    /// Inserts e into an already sorted Self, keeping it sorted. It's
+    /// placed after the elements equal to it, so the insertion is
+    /// stable. If Self isn't sorted, the position of e is unspecified.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayAdd;
+    ///
+    /// let array: [u8; 4] = [1, 2, 4, 5];
+    /// let expected = [1, 2, 3, 4, 5];
+    /// let result = array.insert_sorted(3);
+    /// assert_eq!(expected, result);
+    /// ```
+    fn insert_sorted(self, e: T) -> [T; N + 1]
+    where
+        T: Copy + ~const Ord;
+
+    /// Pads both ends of Self with P elements, mirroring the first and
+    /// last P elements, including the ones at the edges, like the
+    /// symmetric mode of signal processing libraries.
+    /// Won't compile if P > N.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayAdd;
+    ///
+    /// let array: [u8; 3] = [1, 2, 3];
+    /// let expected = [2, 1, 1, 2, 3, 3, 2];
+    /// let result = array.reflect_pad::<2>();
+    /// assert_eq!(expected, result);
+    /// ```
+    fn reflect_pad<const P: usize>(self) -> [T; N + 2 * P]
+    where
+        T: Copy;
+
+    /// Pads both ends of Self with P copies of the first and last
+    /// elements respectively. Won't compile if N == 0.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayAdd;
+    ///
+    /// let array: [u8; 3] = [1, 2, 3];
+    /// let expected = [1, 1, 2, 3, 3];
+    /// let result = array.edge_pad::<1>();
+    /// assert_eq!(expected, result);
+    /// ```
+    fn edge_pad<const P: usize>(self) -> [T; N + 2 * P]
+    where
+        T: Copy;
+
+    /// Joins Self and array, which are expected to overlap by one
+    /// element, like the segments of a polyline: the first element of
+    /// array is assumed to be equal to the last one of Self, so it's
+    /// dropped. This is only checked in debug builds.
+    /// Won't compile if N == 0 or L == 0.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayAdd;
+    ///
+    /// let array: [u8; 3] = [1, 2, 3];
+    /// let expected = [1, 2, 3, 4, 5];
+    /// let result = array.concat_overlap([3, 4, 5]);
+    /// assert_eq!(expected, result);
+    /// ```
+    fn concat_overlap<const L: usize>(self, array: [T; L]) -> [T; N + L - 1]
+    where
+        T: Copy + ~const PartialEq;
+
+    /// Extends Self with the E elements of filler. It's the same as
+    /// concat, but reads better in generic code growing arrays by a
+    /// fixed amount, where chaining push_back would force spelling every
+    /// intermediate length as a bound. Only `[(); N + E]:` is needed.
+    /// # Examples
+    /// ```
+    /// #![allow(incomplete_features)]
+    /// #![feature(generic_const_exprs)]
+    /// use array_manipulation::ArrayAdd;
+    ///
+    /// fn with_crc<const N: usize>(payload: [u8; N]) -> [u8; N + 4]
+    /// where
+    ///     [(); N + 4]:,
+    /// {
+    ///     payload.extend_by([0; 4])
+    /// }
+    ///
+    /// assert_eq!([1, 2, 0, 0, 0, 0], with_crc([1, 2]));
+    /// assert_eq!([0; 4], with_crc([]));
+    /// ```
+    fn extend_by<const E: usize>(self, filler: [T; E]) -> [T; N + E];
+
+    /// Copies Self into an array of M elements, filling the M - N ones
+    /// left at the end with fill, to normalize arrays of different
+    /// lengths to a common one.
+    /// Won't compile if M < N.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayAdd;
+    ///
+    /// let array: [u8; 3] = [1, 2, 3];
+    /// let expected = [1, 2, 3, 0, 0];
+    /// let result = array.pad_to::<5>(0);
+    /// assert_eq!(expected, result);
+    /// ```
+    fn pad_to<const M: usize>(self, fill: T) -> [T; M]
+    where
+        T: Copy;
+}
+
+/// Holds the append methods that pull the elements from an iterator.
+/// Iterators can't be used in `const`, which is why these aren't part
+/// of ArrayAdd.
+pub trait ArrayAddIter<T, const N: usize>: Sized {
+    /// Pulls exactly M elements from iter and appends them at the end of
+    /// Self, or returns None dropping everything if it yields fewer.
+    /// Any element past the M-th one is left in the iterator, so pass
+    /// `by_ref()` to keep using it.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayAddIter;
+    ///
+    /// let header: [u8; 2] = [0xca, 0xfe];
+    /// let payload = vec![1, 2, 3];
+    /// assert_eq!(Some([0xca, 0xfe, 1, 2]), header.append_from_iter::<_, 2>(payload.clone()));
+    /// assert_eq!(None, header.append_from_iter::<_, 4>(payload));
+    /// ```
+    fn append_from_iter<I: IntoIterator<Item = T>, const M: usize>(
+        self,
+        iter: I,
+    ) -> Option<[T; N + M]>;
 }
 
 /// Holds the pop methods.
@@ -101,197 +430,6364 @@ pub trait ArrayRemove<T, const N: usize>: Sized {
     /// assert_eq!(expected, result);
     /// ```
     fn truncate_end<const L: usize>(self) -> [T; N - L];
-}
 
-#[repr(C)]
-struct Contiguous<A, B>(A, B);
+    /// Moves the first element out of Self, returning it alongside
+    /// the rest of the elements. Won't compile if N == 0.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayRemove;
+    ///
+    /// let array: [u8; 4] = [1, 2, 3, 4];
+    /// let expected = (1, [2, 3, 4]);
+    /// let result = array.split_first();
+    /// assert_eq!(expected, result);
+    /// ```
+    fn split_first(self) -> (T, [T; N - 1]);
 
-// from https://github.com/Vurich/const-concat/issues/13#issue-1190857331
-const unsafe fn transmute_unchecked<From, To>(from: From) -> To {
-    union Transmute<From, To> {
-        from: ManuallyDrop<From>,
-        to: ManuallyDrop<To>,
-    }
+    /// Moves the last element out of Self, returning it alongside
+    /// the rest of the elements. Won't compile if N == 0.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayRemove;
+    ///
+    /// let array: [u8; 4] = [1, 2, 3, 4];
+    /// let expected = (4, [1, 2, 3]);
+    /// let result = array.split_last();
+    /// assert_eq!(expected, result);
+    /// ```
+    fn split_last(self) -> (T, [T; N - 1]);
 
-    ManuallyDrop::into_inner(
-        Transmute {
-            from: ManuallyDrop::new(from),
-        }
-        .to,
-    )
+    /// Moves the element at IDX out of Self and fills its slot with
+    /// the last element, like `Vec::swap_remove`. This doesn't preserve
+    /// ordering, but avoids shifting the elements after IDX.
+    /// Won't compile if IDX >= N.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayRemove;
+    ///
+    /// let array: [u8; 4] = [1, 2, 3, 4];
+    /// let expected = (2, [1, 4, 3]);
+    /// let result = array.swap_remove::<1>();
+    /// assert_eq!(expected, result);
+    /// ```
+    fn swap_remove<const IDX: usize>(self) -> (T, [T; N - 1]);
+
+    /// Creates an iterator that moves the elements out of Self one by
+    /// one, from either end.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayRemove;
+    ///
+    /// let array: [u8; 4] = [1, 2, 3, 4];
+    /// let mut iter = array.into_iter_move();
+    /// assert_eq!(Some(1), iter.next());
+    /// assert_eq!(Some(4), iter.next_back());
+    /// assert_eq!(vec![2, 3], iter.collect::<Vec<_>>());
+    /// ```
+    fn into_iter_move(self) -> ArrayMoveIter<T, N>;
+
+    /// Creates a new fixed-size array with the first HEAD and the last
+    /// TAIL elements of Self, dropping the ones in the middle.
+    /// Won't compile if HEAD + TAIL > N.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayRemove;
+    ///
+    /// let array: [u8; 6] = [1, 2, 3, 4, 5, 6];
+    /// let expected = [1, 2, 6];
+    /// let result = array.keep_ends::<2, 1>();
+    /// assert_eq!(expected, result);
+    /// ```
+    fn keep_ends<const HEAD: usize, const TAIL: usize>(self) -> [T; HEAD + TAIL];
+
+    /// Non-lossy counterpart of truncate_start: instead of dropping the
+    /// first L elements, returns them alongside the rest.
+    /// Won't compile if L > N.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayRemove;
+    ///
+    /// let array: [u8; 4] = [1, 2, 3, 4];
+    /// let expected = ([1], [2, 3, 4]);
+    /// let result = array.split_off_start::<1>();
+    /// assert_eq!(expected, result);
+    /// ```
+    fn split_off_start<const L: usize>(self) -> ([T; L], [T; N - L]);
+
+    /// Splits Self into two halves. If N is odd, the second one is
+    /// larger by one element.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayRemove;
+    ///
+    /// let array: [u8; 5] = [1, 2, 3, 4, 5];
+    /// let expected = ([1, 2], [3, 4, 5]);
+    /// let result = array.into_halves();
+    /// assert_eq!(expected, result);
+    /// ```
+    fn into_halves(self) -> ([T; N / 2], [T; N - N / 2]);
+
+    /// Splits Self into four quarters. If N isn't divisible by 4, the
+    /// last one takes the remaining elements, being larger by up to
+    /// three elements.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayRemove;
+    ///
+    /// let array: [u8; 6] = [1, 2, 3, 4, 5, 6];
+    /// let expected = ([1], [2], [3], [4, 5, 6]);
+    /// let result = array.into_quarters();
+    /// assert_eq!(expected, result);
+    /// ```
+    fn into_quarters(self) -> ([T; N / 4], [T; N / 4], [T; N / 4], [T; N - 3 * (N / 4)]);
 }
 
-impl<T, const N: usize> const ArrayAdd<T, N> for [T; N] {
-    fn concat<const L: usize>(self, array: [T; L]) -> [T; N + L] {
-        unsafe {
-            // join contiguous memory in a single array
-            transmute_unchecked(Contiguous(self, array))
-        }
-    }
+/// By-value iterator over the elements of an array, created by
+/// `ArrayRemove::into_iter_move`. The elements that don't get
+/// yielded are dropped alongside the iterator.
+pub struct ArrayMoveIter<T, const N: usize> {
+    array: [MaybeUninit<T>; N],
+    start: usize,
+    end: usize,
+}
 
-    fn concat_back<const L: usize>(self, array: [T; L]) -> [T; N + L] {
-        unsafe {
-            // join contiguous memory in a single array
-            transmute_unchecked(Contiguous(array, self))
+impl<T, const N: usize> ArrayMoveIter<T, N> {
+    const fn new(array: [T; N]) -> Self {
+        Self {
+            array: unsafe { transmute_unchecked(array) }, // mark every element as maybe moved
+            start: 0,
+            end: N,
         }
     }
+}
 
-    fn append(self, element: T) -> [T; N + 1] {
-        unsafe {
-            // join contiguous memory in a single array
-            transmute_unchecked(Contiguous(self, element))
+impl<T, const N: usize> Iterator for ArrayMoveIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None;
         }
+        self.start += 1;
+        Some(unsafe { self.array[self.start - 1].assume_init_read() }) // move out the front
     }
 
-    fn append_back(self, element: T) -> [T; N + 1] {
-        unsafe {
-            // join contiguous memory in a single array
-            transmute_unchecked(Contiguous(element, self))
-        }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.end - self.start, Some(self.end - self.start))
     }
 }
 
-impl<T, const N: usize> ArrayRemove<T, N> for [T; N] {
-    default fn truncate_start<const L: usize>(mut self) -> [T; N - L] {
-        unsafe {
-            let result = read((&raw const self).cast::<T>().add(L).cast()); // copy from offset'ed pointer
-            drop_in_place(&raw mut self[..L]); // drop popped elements
-            forget(self); // avoid drop & deallocation of the copied elements
-            result
+impl<T, const N: usize> DoubleEndedIterator for ArrayMoveIter<T, N> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None;
         }
+        self.end -= 1;
+        Some(unsafe { self.array[self.end].assume_init_read() }) // move out the back
     }
+}
 
-    default fn truncate_end<const L: usize>(mut self) -> [T; N - L] {
-        unsafe {
-            drop_in_place(&raw mut self[L..]); // drop popped elements
-            transmute_unchecked(self) // resize self
-        }
+impl<T, const N: usize> ExactSizeIterator for ArrayMoveIter<T, N> {}
+
+impl<T, const N: usize> Drop for ArrayMoveIter<T, N> {
+    fn drop(&mut self) {
+        let remaining = &mut self.array[self.start..self.end] as *mut [MaybeUninit<T>];
+        unsafe { drop_in_place(remaining as *mut [T]) } // drop the elements not yielded
     }
 }
 
-impl<T: Copy, const N: usize> const ArrayRemove<T, N> for [T; N] {
-    fn truncate_start<const L: usize>(self) -> [T; N - L] {
-        unsafe {
-            read((&raw const self).cast::<T>().add(L).cast()) // copy from offset'ed pointer
+/// Fixed-capacity buffer that gets filled in place, to build big
+/// arrays without copying the whole of them on every append, the way
+/// chaining `ArrayAdd::push_back` would. The elements pushed are
+/// dropped alongside it, unless it's turned into an array.
+/// # Examples
+/// ```
+/// use array_manipulation::ConstVec;
+///
+/// let mut vec = ConstVec::<u8, 4>::new();
+/// assert_eq!(Ok(()), vec.push(1));
+/// assert_eq!(Ok(()), vec.extend_from_array([2, 3, 4]));
+/// assert_eq!(Err(5), vec.push(5));
+/// assert_eq!(Ok([1, 2, 3, 4]), vec.into_array::<4>().map_err(|_| ()));
+/// ```
+pub struct ConstVec<T, const CAP: usize> {
+    array: [MaybeUninit<T>; CAP],
+    len: usize,
+}
+
+impl<T, const CAP: usize> ConstVec<T, CAP> {
+    /// Creates an empty buffer.
+    pub const fn new() -> Self {
+        Self {
+            // an array of uninitialized slots doesn't need initialization
+            array: unsafe { MaybeUninit::<[MaybeUninit<T>; CAP]>::uninit().assume_init() },
+            len: 0,
         }
     }
 
-    fn truncate_end<const L: usize>(self) -> [T; N - L] {
-        unsafe {
-            transmute_unchecked(self) // resize self
-        }
+    /// Returns the amount of elements pushed.
+    pub const fn len(&self) -> usize {
+        self.len
     }
+
+    /// Returns whether no element has been pushed.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Moves e behind the elements already pushed,
+    /// or gives it back if the buffer is full.
+    pub const fn push(&mut self, e: T) -> Result<(), T> {
+        if self.len == CAP {
+            return Err(e);
+        }
+        self.array[self.len] = MaybeUninit::new(e);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Moves the elements of array behind the elements already pushed,
+    /// or gives it back if they don't fit. It's copied in a single go.
+    pub const fn extend_from_array<const L: usize>(&mut self, array: [T; L]) -> Result<(), [T; L]> {
+        if CAP - self.len < L {
+            return Err(array);
+        }
+        unsafe {
+            let out = self.array.as_mut_ptr().add(self.len).cast::<[T; L]>();
+            out.write(array);
+        }
+        self.len += L;
+        Ok(())
+    }
+
+    /// Returns the elements pushed.
+    pub const fn as_slice(&self) -> &[T] {
+        unsafe { &*slice_from_raw_parts(self.array.as_ptr().cast::<T>(), self.len) }
+    }
+
+    /// Moves the elements pushed into an array, or gives Self back if
+    /// their amount isn't N.
+    pub const fn into_array<const N: usize>(self) -> Result<[T; N], Self> {
+        if self.len != N {
+            return Err(self);
+        }
+        let this = ManuallyDrop::new(self);
+        unsafe { Ok(read((&raw const this.array).cast::<[T; N]>())) } // move out the pushed elements
+    }
+}
+
+impl<T, const CAP: usize> Default for ConstVec<T, CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const CAP: usize> Drop for ConstVec<T, CAP> {
+    fn drop(&mut self) {
+        let pushed = &mut self.array[..self.len] as *mut [MaybeUninit<T>];
+        unsafe { drop_in_place(pushed as *mut [T]) } // drop the elements pushed
+    }
+}
+
+/// Holds the methods that split Self into several sub-arrays.
+/// Will (probably) get into core when
+/// [generic-const-exprs](https://doc.rust-lang.org/beta/unstable-book/language-features/generic-const-exprs.html)
+/// becomes complete.
+pub trait ArraySplit<T, const N: usize>: Sized {
+    /// Creates a new fixed-size array holding every overlapping
+    /// window of W elements, like `slice::windows`. As windows share
+    /// elements, T must be Copy. Won't compile if W == 0 or W > N.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArraySplit;
+    ///
+    /// let array: [u8; 4] = [1, 2, 3, 4];
+    /// let expected = [[1, 2], [2, 3], [3, 4]];
+    /// let result = array.windows::<2>();
+    /// assert_eq!(expected, result);
+    /// ```
+    fn windows<const W: usize>(self) -> [[T; W]; N - W + 1]
+    where
+        T: Copy;
+
+    /// Passes every overlapping window of W elements through f, in
+    /// order, creating a new fixed-size array with the results. Unlike
+    /// `windows`, the windows are borrowed from Self instead of copied.
+    /// Won't compile if W == 0 or W > N.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArraySplit;
+    ///
+    /// let array: [u8; 5] = [1, 2, 3, 4, 5];
+    /// let expected = [6, 9, 12];
+    /// let result = array.windows_map::<3, u8, _>(|w| w.iter().sum());
+    /// assert_eq!(expected, result);
+    /// ```
+    fn windows_map<const W: usize, U, F: ~const FnMut(&[T; W]) -> U + ~const Destruct>(
+        self,
+        f: F,
+    ) -> [U; N - W + 1]
+    where
+        T: Copy;
+
+    /// Reverse of `ArrayAdd::interleave`. Moves the elements at even
+    /// indices into the first array and the ones at odd indices into
+    /// the second one. Won't compile if N is odd.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArraySplit;
+    ///
+    /// let array: [u8; 6] = [1, 2, 3, 4, 5, 6];
+    /// let expected = ([1, 3, 5], [2, 4, 6]);
+    /// let result = array.deinterleave();
+    /// assert_eq!(expected, result);
+    /// ```
+    fn deinterleave(self) -> ([T; N / 2], [T; N / 2]);
+
+    /// Calls f on each of the N / C non-overlapping chunks of Self, in
+    /// place and in order, like `slice::chunks_exact_mut`.
+    /// Won't compile if N isn't a multiple of C.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArraySplit;
+    ///
+    /// let mut array: [u8; 6] = [1, 2, 3, 4, 5, 6];
+    /// array.for_each_chunk::<2, _>(|chunk| chunk.swap(0, 1));
+    /// assert_eq!([2, 1, 4, 3, 6, 5], array);
+    /// ```
+    fn for_each_chunk<const C: usize, F: ~const FnMut(&mut [T; C]) + ~const Destruct>(
+        &mut self,
+        f: F,
+    );
+
+    /// Views the storage of Self as two fixed-size arrays, the first
+    /// one holding the first M elements and the second the rest.
+    /// No element is copied. Won't compile if M > N.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArraySplit;
+    ///
+    /// let packet: [u8; 6] = [0xca, 0xfe, 1, 2, 3, 4];
+    /// let (header, payload) = packet.split_ref::<2>();
+    /// assert_eq!(&[0xca, 0xfe], header);
+    /// assert_eq!(&[1, 2, 3, 4], payload);
+    /// ```
+    fn split_ref<const M: usize>(&self) -> (&[T; M], &[T; N - M]);
+
+    /// Mutable counterpart of split_ref. As both halves never overlap,
+    /// they can be filled independently, even from different threads.
+    /// Won't compile if M > N.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArraySplit;
+    ///
+    /// let mut packet: [u8; 6] = [0; 6];
+    /// let (header, payload) = packet.split_mut::<2>();
+    /// *header = [0xca, 0xfe];
+    /// payload[0] = 1;
+    /// assert_eq!([0xca, 0xfe, 1, 0, 0, 0], packet);
+    /// ```
+    /// ```
+    /// use array_manipulation::ArraySplit;
+    ///
+    /// let mut squares: [u32; 8] = [0; 8];
+    /// let (low, high) = squares.split_mut::<4>();
+    /// std::thread::scope(|s| {
+    ///     s.spawn(|| low.iter_mut().zip(0..).for_each(|(e, i)| *e = i * i));
+    ///     s.spawn(|| high.iter_mut().zip(4..).for_each(|(e, i)| *e = i * i));
+    /// });
+    /// assert_eq!([0, 1, 4, 9, 16, 25, 36, 49], squares);
+    /// ```
+    fn split_mut<const M: usize>(&mut self) -> (&mut [T; M], &mut [T; N - M]);
+
+    /// Groups every two consecutive elements of Self into a pair.
+    /// As it only reinterprets the layout, no element is copied.
+    /// Won't compile if N is odd.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArraySplit;
+    ///
+    /// let coordinates: [u8; 4] = [1, 2, 3, 4];
+    /// let expected = [[1, 2], [3, 4]];
+    /// let result = coordinates.group_pairs();
+    /// assert_eq!(expected, result);
+    /// ```
+    fn group_pairs(self) -> [[T; 2]; N / 2];
+
+    /// Splits Self into chunks of C elements starting from the end, so
+    /// the first chunk holds the last C elements, like `slice::rchunks`.
+    /// The elements of each chunk keep their order.
+    /// Won't compile if N isn't divisible by C.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArraySplit;
+    ///
+    /// let array: [u8; 6] = [1, 2, 3, 4, 5, 6];
+    /// let expected = [[5, 6], [3, 4], [1, 2]];
+    /// let result = array.rchunks::<2>();
+    /// assert_eq!(expected, result);
+    /// ```
+    fn rchunks<const C: usize>(self) -> [[T; C]; N / C];
+
+    /// Splits Self into chunks of C elements from the start, returning
+    /// the elements that don't fill a whole chunk separately.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArraySplit;
+    ///
+    /// let array: [u8; 7] = [1, 2, 3, 4, 5, 6, 7];
+    /// let (chunks, remainder) = array.chunks_with_remainder::<3>();
+    /// assert_eq!([[1, 2, 3], [4, 5, 6]], chunks);
+    /// assert_eq!([7], remainder);
+    /// ```
+    fn chunks_with_remainder<const C: usize>(self) -> ([[T; C]; N / C], [T; N % C]);
+
+    /// Folds the chunks of C elements of Self, in order, passing each
+    /// one to f by reference straight from the storage of Self.
+    /// Won't compile if N isn't divisible by C.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArraySplit;
+    ///
+    /// let array: [u8; 4] = [1, 2, 3, 4];
+    /// let result = array.fold_chunks::<2, _, _>(0, |acc, c| acc + c[0] * c[1]);
+    /// assert_eq!(14, result);
+    /// ```
+    fn fold_chunks<const C: usize, B, F: ~const FnMut(B, &[T; C]) -> B + ~const Destruct>(
+        self,
+        init: B,
+        f: F,
+    ) -> B
+    where
+        T: Copy;
+
+    /// Moves every C-sized chunk of Self into f, in order, and joins the
+    /// chunks it returns, like for block ciphers. The remaining chunks
+    /// are dropped if f panics.
+    /// Won't compile if N isn't divisible by C.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArraySplit;
+    ///
+    /// let array: [u8; 6] = [1, 2, 3, 4, 5, 6];
+    /// let expected = [3, 2, 1, 6, 5, 4];
+    /// let result = array.map_chunks(|[a, b, c]: [u8; 3]| [c, b, a]);
+    /// assert_eq!(expected, result);
+    /// ```
+    fn map_chunks<const C: usize, U, F: ~const FnMut([T; C]) -> [U; C] + ~const Destruct>(
+        self,
+        f: F,
+    ) -> [U; C * (N / C)];
+}
+
+/// Holds the methods that build arrays from scratch.
+pub trait ArrayInit<T, const N: usize>: Sized {
+    /// Creates a new fixed-size array with every element set to e.
+    /// It's the same as `[e; N]`, but callable from generic code
+    /// through this trait, including `const` contexts.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayInit;
+    ///
+    /// let expected: [u8; 4] = [7, 7, 7, 7];
+    /// let result = <[u8; 4]>::fill(7);
+    /// assert_eq!(expected, result);
+    /// ```
+    fn fill(e: T) -> [T; N]
+    where
+        T: Copy;
+
+    /// Copies the elements of a slice into a new fixed-size array if
+    /// it holds exactly N of them, otherwise returns None.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayInit;
+    ///
+    /// let slice: &[u8] = &[1, 2, 3, 4];
+    /// assert_eq!(Some([1, 2, 3, 4]), <[u8; 4]>::from_slice(slice));
+    /// assert_eq!(None, <[u8; 3]>::from_slice(slice));
+    /// ```
+    fn from_slice(slice: &[T]) -> Option<[T; N]>
+    where
+        T: Copy;
+
+    /// Same as from_slice, but if the slice is shorter than N, the
+    /// missing trailing elements are created by calling f with their
+    /// indexes. Returns None if the slice is longer than N.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayInit;
+    ///
+    /// let slice: &[u8] = &[1, 2];
+    /// assert_eq!(Some([1, 2, 0, 0]), <[u8; 4]>::from_slice_or(slice, |_| 0));
+    /// assert_eq!(None, <[u8; 1]>::from_slice_or(slice, |_| 0));
+    /// ```
+    fn from_slice_or<F: ~const FnMut(usize) -> T + ~const Destruct>(
+        slice: &[T],
+        f: F,
+    ) -> Option<[T; N]>
+    where
+        T: Copy;
+
+    /// Creates a new fixed-size array by calling f with the index of
+    /// every element, in order, like `core::array::from_fn`, but usable
+    /// in `const` contexts. If f panics, the elements already created
+    /// are dropped.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayInit;
+    ///
+    /// let expected: [usize; 4] = [0, 1, 4, 9];
+    /// let result = <[usize; 4]>::build(|i| i * i);
+    /// assert_eq!(expected, result);
+    /// ```
+    fn build<F: ~const FnMut(usize) -> T + ~const Destruct>(f: F) -> [T; N];
+
+    /// Same as build, but stops at the first error f returns, dropping
+    /// the elements already created and returning it.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayInit;
+    ///
+    /// let digits = "12a4";
+    /// let parse = |i: usize| digits[i..i + 1].parse::<u8>();
+    /// assert_eq!(Ok([1, 2]), <[u8; 2]>::try_build(parse));
+    /// assert!(<[u8; 4]>::try_build(parse).is_err());
+    /// ```
+    fn try_build<E: ~const Destruct, F: ~const FnMut(usize) -> Result<T, E> + ~const Destruct>(
+        f: F,
+    ) -> Result<[T; N], E>
+    where
+        T: ~const Destruct;
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::{ArrayAdd, ArrayRemove};
+/// Holds the methods that transform Self without changing its length.
+pub trait ArrayTransform<T, const N: usize>: Sized {
+    /// Moves the element at IDX out of Self, passes it through f and
+    /// writes the result back in place, leaving its neighbours untouched.
+    /// If f panics, the rest of the elements are dropped.
+    /// Won't compile if IDX >= N.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [u8; 4] = [1, 2, 3, 4];
+    /// let expected = [1, 2, 4, 4];
+    /// let result = array.update_at::<2, _>(|e| e + 1);
+    /// assert_eq!(expected, result);
+    /// ```
+    fn update_at<const IDX: usize, F: ~const FnOnce(T) -> T + ~const Destruct>(
+        self,
+        f: F,
+    ) -> [T; N];
+
+    /// Sorts Self in ascending order with an insertion sort, so it can
+    /// be used to precompute sorted tables in `const` contexts. It is
+    /// stable, but quadratic, so consider `slice::sort` for big arrays.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [u8; 4] = [3, 1, 4, 2];
+    /// let expected = [1, 2, 3, 4];
+    /// let result = array.sort();
+    /// assert_eq!(expected, result);
+    /// ```
+    #[must_use = "returns a new array, unlike `slice::sort`, which works in place"]
+    fn sort(self) -> [T; N]
+    where
+        T: Copy + ~const Ord;
+
+    /// Replaces the element at a runtime index with e, returning Self
+    /// alongside the element it held. If idx is out of bounds, both
+    /// Self and e are given back untouched in the error.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [u8; 4] = [1, 2, 3, 4];
+    /// assert_eq!(Ok(([1, 9, 3, 4], 2)), array.get_and_replace(1, 9));
+    /// assert_eq!(Err(([1, 2, 3, 4], 9)), array.get_and_replace(4, 9));
+    /// ```
+    fn get_and_replace(self, idx: usize, e: T) -> Result<([T; N], T), ([T; N], T)>;
+
+    /// Swaps the elements at I and J only if condition is true, without
+    /// branching on it: both elements are always read and written back,
+    /// mixed through a mask derived from condition, which is why T must
+    /// be one of the primitive integers.
+    /// This is meant for constant-time code, but the compiler is still
+    /// free to introduce branches, so check the codegen if it's critical.
+    /// Won't compile if I >= N or J >= N.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [u32; 3] = [1, 2, 3];
+    /// assert_eq!([3, 2, 1], array.cswap::<0, 2>(true));
+    /// assert_eq!([1, 2, 3], array.cswap::<0, 2>(false));
+    /// ```
+    fn cswap<const I: usize, const J: usize>(self, condition: bool) -> [T; N]
+    where
+        T: ~const PrimitiveInt;
+
+    /// Rotates Self k elements to the left, so the element at k becomes
+    /// the first one. k is reduced modulo N, and can be unknown at
+    /// compile time.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [u8; 5] = [1, 2, 3, 4, 5];
+    /// let expected = [3, 4, 5, 1, 2];
+    /// let result = array.rotate_left_by(2);
+    /// assert_eq!(expected, result);
+    /// ```
+    #[must_use = "returns a new array, unlike `slice::rotate_left`, which works in place"]
+    fn rotate_left_by(self, k: usize) -> [T; N]
+    where
+        T: Copy;
+
+    /// Moves the first element of every run of consecutive equal
+    /// elements to the front, returning the amount of them. As the
+    /// length can't shrink, the rest of the elements are left as copies,
+    /// so only `result[..count]` is meaningful.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [u8; 6] = [1, 1, 2, 3, 3, 3];
+    /// let (result, count) = array.dedup_consecutive();
+    /// assert_eq!([1, 2, 3], result[..count]);
+    /// ```
+    fn dedup_consecutive(self) -> ([T; N], usize)
+    where
+        T: Copy + ~const PartialEq;
+
+    /// Folds Self from the start, returning every intermediate
+    /// accumulator instead of just the last one, like prefix sums.
+    /// The i-th element of the result is the accumulator after
+    /// passing the i-th element of Self through f.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [u8; 4] = [1, 2, 3, 4];
+    /// let expected = [1, 3, 6, 10];
+    /// let result = array.scan_const(0, |acc, e| acc + e);
+    /// assert_eq!(expected, result);
+    /// ```
+    fn scan_const<U: Copy, F: ~const FnMut(U, &T) -> U + ~const Destruct>(
+        self,
+        init: U,
+        f: F,
+    ) -> [U; N];
+
+    /// Moves each element of Self through f, building a new array with
+    /// the results, or stopping at the first error and returning it.
+    /// When that happens, the elements both already converted and not
+    /// yet converted are dropped. Only `const` when T: Copy, as the impl
+    /// moving other element types out of Self isn't.
+    /// Named this way to not collide with the unstable `array::try_map`.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [i32; 3] = [1, 2, 3];
+    /// assert_eq!(Ok([1, 2, 3]), array.try_map_each(u8::try_from));
+    /// let array: [i32; 3] = [1, -2, 3];
+    /// assert!(array.try_map_each(u8::try_from).is_err());
+    /// ```
+    fn try_map_each<
+        U: ~const Destruct,
+        E: ~const Destruct,
+        F: ~const FnMut(T) -> Result<U, E> + ~const Destruct,
+    >(
+        self,
+        f: F,
+    ) -> Result<[U; N], E>;
+
+    /// Writes each element of values into Self at the index given by
+    /// the same position in indices, the inverse of `ArrayQuery::gather`.
+    /// If an index is repeated, the value written last wins.
+    /// # Panics
+    /// If any index is out of bounds.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [u8; 4] = [1, 2, 3, 4];
+    /// let expected = [1, 9, 3, 7];
+    /// let result = array.scatter([3, 1], [7, 9]);
+    /// assert_eq!(expected, result);
+    /// ```
+    fn scatter<const M: usize>(self, indices: [usize; M], values: [T; M]) -> [T; N]
+    where
+        T: Copy;
+
+    /// Pairs each element with its index, moving it into the second
+    /// field of the tuple, so the original positions can be recovered
+    /// after reordering the array.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [char; 3] = ['a', 'b', 'c'];
+    /// let expected = [(0, 'a'), (1, 'b'), (2, 'c')];
+    /// let result = array.enumerate_const();
+    /// assert_eq!(expected, result);
+    /// ```
+    fn enumerate_const(self) -> [(usize, T); N];
+
+    /// Moves each element of Self into f along with the accumulator,
+    /// starting from init, and returns the last accumulator.
+    /// If f panics, the elements not yet moved into it are dropped.
+    /// Only `const` when T: Copy, other element types are moved out
+    /// through `ArrayMoveIter`, which can only run at runtime.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [u8; 4] = [1, 2, 3, 4];
+    /// let result = array.fold_const(0u32, |acc, e| acc + e as u32);
+    /// assert_eq!(10, result);
+    /// ```
+    fn fold_const<B, F: ~const FnMut(B, T) -> B + ~const Destruct>(self, init: B, f: F) -> B;
+
+    /// Same as `fold_const`, but uses the first element as the initial
+    /// accumulator, returning None if Self is empty. Also only `const`
+    /// when T: Copy.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [u16; 3] = [1, 2, 3];
+    /// assert_eq!(Some(123), array.reduce_const(|acc, e| acc * 10 + e));
+    /// assert_eq!(None, [0u8; 0].reduce_const(|acc, e| acc ^ e));
+    /// ```
+    fn reduce_const<F: ~const FnMut(T, T) -> T + ~const Destruct>(self, f: F) -> Option<T>;
+
+    /// Copies LEN elements starting at SRC over the ones starting at DST,
+    /// like `slice::copy_within`. The ranges may overlap.
+    /// Won't compile if SRC + LEN > N or DST + LEN > N.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [u8; 5] = [1, 2, 3, 4, 5];
+    /// let expected = [1, 1, 2, 3, 5];
+    /// let result = array.copy_within_const::<0, 3, 1>();
+    /// assert_eq!(expected, result);
+    /// ```
+    #[must_use = "returns a new array, unlike `slice::copy_within`, which works in place"]
+    fn copy_within_const<const SRC: usize, const LEN: usize, const DST: usize>(self) -> [T; N]
+    where
+        T: Copy;
+
+    /// Combines each element of Self with the one at the same index of
+    /// other through bitwise XOR.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [u8; 2] = [0b1100, 0b1010];
+    /// let expected = [0b0110, 0b0000];
+    /// let result = array.xor([0b1010, 0b1010]);
+    /// assert_eq!(expected, result);
+    /// ```
+    fn xor(self, other: [T; N]) -> [T; N]
+    where
+        T: Copy + ~const BitXor<Output = T>;
+
+    /// Combines each element of Self with the one at the same index of
+    /// other through bitwise AND.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [u8; 2] = [0b1100, 0b1010];
+    /// let expected = [0b1000, 0b1010];
+    /// let result = array.and([0b1010, 0b1010]);
+    /// assert_eq!(expected, result);
+    /// ```
+    fn and(self, other: [T; N]) -> [T; N]
+    where
+        T: Copy + ~const BitAnd<Output = T>;
+
+    /// Combines each element of Self with the one at the same index of
+    /// other through bitwise OR.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [u8; 2] = [0b1100, 0b1010];
+    /// let expected = [0b1110, 0b1010];
+    /// let result = array.or([0b1010, 0b1010]);
+    /// assert_eq!(expected, result);
+    /// ```
+    fn or(self, other: [T; N]) -> [T; N]
+    where
+        T: Copy + ~const BitOr<Output = T>;
+
+    /// Moves the elements for which pred returns true to the front,
+    /// keeping their order, and returns the amount of them. As the
+    /// length can't shrink, the rest of the elements are left as copies,
+    /// so only `result[..count]` is meaningful.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [u8; 6] = [1, 2, 3, 4, 5, 6];
+    /// let (result, count) = array.compact(|e| e.is_multiple_of(2));
+    /// assert_eq!([2, 4, 6], result[..count]);
+    /// ```
+    fn compact<F: ~const FnMut(&T) -> bool + ~const Destruct>(self, pred: F) -> ([T; N], usize)
+    where
+        T: Copy;
+
+    /// Normalizes Self as a ring buffer whose logical start is at head,
+    /// moving the element at head to the first slot and keeping the
+    /// order from there on, wrapping around. head is reduced modulo N.
+    /// This is the same as `rotate_left_by(head)`.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// // 4 was written last, wrapping around to the start
+    /// let ring: [u8; 5] = [3, 4, 0, 1, 2];
+    /// let expected = [0, 1, 2, 3, 4];
+    /// let result = ring.rotate_to_front(2);
+    /// assert_eq!(expected, result);
+    /// ```
+    #[must_use = "returns a new array, unlike `slice::rotate_left`, which works in place"]
+    fn rotate_to_front(self, head: usize) -> [T; N]
+    where
+        T: Copy;
+
+    /// Splits Self into the elements for which pred returns true and
+    /// the rest, keeping their order, returning both alongside their
+    /// amounts. As the lengths can't shrink, only `kept[..kept_count]`
+    /// & `removed[..removed_count]` are meaningful, the rest of the
+    /// elements of both arrays being left as unspecified copies.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [u8; 5] = [1, 2, 3, 4, 5];
+    /// let (kept, removed, kept_count, removed_count) = array.split_by(|e| *e > 2);
+    /// assert_eq!([3, 4, 5], kept[..kept_count]);
+    /// assert_eq!([1, 2], removed[..removed_count]);
+    /// ```
+    fn split_by<F: ~const FnMut(&T) -> bool + ~const Destruct>(
+        self,
+        pred: F,
+    ) -> ([T; N], [T; N], usize, usize)
+    where
+        T: Copy;
+
+    /// Passes each element of Self through f, keeping its type. Unlike
+    /// `array::map`, it can be used in `const` contexts if T is Copy.
+    /// Handy for fixing the endianness of decoded words, among others.
+    /// If f panics, the elements not yet mapped and the already mapped
+    /// ones are dropped.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [u16; 2] = [0x0102, 0x0304];
+    /// let expected = [0x0201, 0x0403];
+    /// let result = array.map_each(u16::swap_bytes);
+    /// assert_eq!(expected, result);
+    /// ```
+    fn map_each<F: ~const FnMut(T) -> T + ~const Destruct>(self, f: F) -> [T; N];
+
+    /// Reorders Self so the elements smaller than pivot come first,
+    /// returning the amount of them, which is the index where the rest
+    /// start. The relative order of the elements isn't kept.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [u8; 5] = [3, 1, 4, 1, 5];
+    /// let (result, split) = array.partition_around(3);
+    /// assert_eq!(2, split);
+    /// assert!(result[..split].iter().all(|e| *e < 3));
+    /// assert!(result[split..].iter().all(|e| *e >= 3));
+    /// ```
+    fn partition_around(self, pivot: T) -> ([T; N], usize)
+    where
+        T: Copy + ~const Ord;
+
+    /// Finds the element that would be at k if Self were sorted with a
+    /// quickselect, without fully sorting it. It's returned alongside
+    /// Self reordered so that it's at k, the elements before it aren't
+    /// greater and the ones after it aren't smaller, like
+    /// `slice::select_nth_unstable`.
+    /// # Panics
+    /// If k is out of bounds.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let samples: [u8; 5] = [5, 3, 1, 4, 2];
+    /// let (median, result) = samples.select_nth(2);
+    /// assert_eq!(3, median);
+    /// assert_eq!(3, result[2]);
+    /// ```
+    fn select_nth(self, k: usize) -> (T, [T; N])
+    where
+        T: Copy + ~const Ord;
+
+    /// Rotates Self K elements to the left in place, so the element at K
+    /// becomes the first one. K is reduced modulo N. Unlike
+    /// `rotate_left_by`, it doesn't need a second array as scratch, as
+    /// it follows each cycle of the permutation moving one element at a
+    /// time, needing a single T as temporary. Prefer this one when N is
+    /// large and the stack is tight.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let mut array: [u8; 6] = [1, 2, 3, 4, 5, 6];
+    /// array.rotate_left_inplace::<4>();
+    /// assert_eq!([5, 6, 1, 2, 3, 4], array);
+    /// ```
+    fn rotate_left_inplace<const K: usize>(&mut self);
+
+    /// Removes every duplicate of an already sorted Self, moving the
+    /// unique elements to the front and returning their amount. As equal
+    /// elements are adjacent once sorted, this is dedup_consecutive under
+    /// another name; paired with sort & binary_search it builds lookup
+    /// tables. If Self isn't sorted, only consecutive duplicates are
+    /// removed. Only `result[..count]` is meaningful.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [u8; 6] = [3, 1, 2, 3, 1, 3];
+    /// let (table, count) = array.sort().dedup_sorted();
+    /// assert_eq!([1, 2, 3], table[..count]);
+    /// assert_eq!(Ok(1), table[..count].binary_search(&2));
+    /// ```
+    fn dedup_sorted(self) -> ([T; N], usize)
+    where
+        T: Copy + ~const PartialEq;
+
+    /// Replaces every element with the greatest one up to it, producing
+    /// the running maximum of Self.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [u8; 5] = [1, 3, 2, 5, 4];
+    /// assert_eq!([1, 3, 3, 5, 5], array.cummax());
+    /// ```
+    fn cummax(self) -> [T; N]
+    where
+        T: Copy + ~const Ord;
+
+    /// Replaces every element with the least one up to it, producing
+    /// the running minimum of Self.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [u8; 5] = [4, 5, 2, 3, 1];
+    /// assert_eq!([4, 4, 2, 2, 1], array.cummin());
+    /// ```
+    fn cummin(self) -> [T; N]
+    where
+        T: Copy + ~const Ord;
+
+    /// Overwrites the P elements of Self starting at index at with the
+    /// ones of with, keeping the length, to patch fixed records.
+    /// # Panics
+    /// If `at + P > N`.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let record: [u8; 6] = *b"id=??;";
+    /// let expected = *b"id=42;";
+    /// let result = record.replace(3, *b"42");
+    /// assert_eq!(expected, result);
+    /// ```
+    fn replace<const P: usize>(self, at: usize, with: [T; P]) -> [T; N]
+    where
+        T: Copy;
+
+    /// Shifts the first K elements out of Self and fills the back with fill,
+    /// returning the evicted elements alongside the new Self, like a
+    /// FIFO window advancing by K.
+    /// Won't compile if K > N.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let window: [u8; 4] = [1, 2, 3, 4];
+    /// let (evicted, window) = window.shift_left([5]);
+    /// assert_eq!(([1], [2, 3, 4, 5]), (evicted, window));
+    /// ```
+    fn shift_left<const K: usize>(self, fill: [T; K]) -> ([T; K], [T; N])
+    where
+        T: Copy;
+
+    /// Reverses the order of the elements of Self in place, swapping
+    /// them pairwise from both ends, so no second array is needed.
+    /// Unlike `slice::reverse`, it can be used in `const`.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let mut array: [u8; 5] = [1, 2, 3, 4, 5];
+    /// array.reverse_inplace();
+    /// assert_eq!([5, 4, 3, 2, 1], array);
+    /// ```
+    fn reverse_inplace(&mut self);
+
+    /// Blends Self and other, taking the element of Self at every index
+    /// where mask is true and the one of other elsewhere. The elements
+    /// not taken are dropped. For Copy types it's branch-free, so it
+    /// pairs well with `ArrayQuery::mask`.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayTransform;
+    ///
+    /// let array: [u8; 4] = [1, 2, 3, 4];
+    /// let expected = [1, 20, 3, 40];
+    /// let result = array.select([10, 20, 30, 40], [true, false, true, false]);
+    /// assert_eq!(expected, result);
+    /// ```
+    fn select(self, other: [T; N], mask: [bool; N]) -> [T; N];
+}
+
+/// Holds the methods that inspect Self without consuming it.
+pub trait ArrayQuery<T, const N: usize> {
+    /// Returns the index of the first element equal to target, if any.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let array: [u8; 4] = [1, 2, 3, 2];
+    /// assert_eq!(Some(1), array.position(&2));
+    /// assert_eq!(None, array.position(&5));
+    /// ```
+    fn position(&self, target: &T) -> Option<usize>
+    where
+        T: ~const PartialEq;
+
+    /// Returns whether any element is equal to target.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let array: [u8; 4] = [1, 2, 3, 4];
+    /// assert!(array.contains(&2));
+    /// assert!(!array.contains(&5));
+    /// ```
+    fn contains(&self, target: &T) -> bool
+    where
+        T: ~const PartialEq;
+
+    /// Returns the index of the first element for which pred returns
+    /// false, assuming Self is partitioned by it (all the elements it
+    /// holds for come first), like `slice::partition_point`.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let array: [u8; 6] = [1, 2, 3, 5, 8, 13];
+    /// assert_eq!(3, array.partition_point(|e| *e < 4));
+    /// ```
+    fn partition_point<F: ~const Fn(&T) -> bool + ~const Destruct>(&self, pred: F) -> usize;
+
+    /// Creates a new fixed-size array with copies of the elements at
+    /// the given indexes, in order, which may repeat.
+    /// # Panics
+    /// If any index is out of bounds.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let array: [u8; 4] = [10, 20, 30, 40];
+    /// let expected = [40, 10, 10];
+    /// let result = array.gather([3, 0, 0]);
+    /// assert_eq!(expected, result);
+    /// ```
+    fn gather<const M: usize>(&self, indices: [usize; M]) -> [T; M]
+    where
+        T: Copy;
+
+    /// Returns the indexes that would sort Self in ascending order,
+    /// without moving any element, so parallel arrays can be reordered
+    /// alongside it with `gather`. Equal elements keep their order, but
+    /// the sort is quadratic, same as `ArrayTransform::sort`.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let array: [u8; 3] = [30, 10, 20];
+    /// let expected = [1, 2, 0];
+    /// let result = array.argsort();
+    /// assert_eq!(expected, result);
+    /// ```
+    fn argsort(&self) -> [usize; N]
+    where
+        T: ~const Ord;
+
+    /// Returns the smallest element, or None if Self is empty.
+    /// If several are equally small, the first one is returned.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let array: [u8; 4] = [3, 1, 4, 1];
+    /// assert_eq!(Some(&1), array.min_const());
+    /// assert_eq!(None, [0u8; 0].min_const());
+    /// ```
+    fn min_const(&self) -> Option<&T>
+    where
+        T: ~const Ord;
+
+    /// Returns the greatest element, or None if Self is empty.
+    /// If several are equally great, the last one is returned.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let array: [u8; 4] = [3, 1, 4, 1];
+    /// assert_eq!(Some(&4), array.max_const());
+    /// assert_eq!(None, [0u8; 0].max_const());
+    /// ```
+    fn max_const(&self) -> Option<&T>
+    where
+        T: ~const Ord;
+
+    /// Returns the element for which f returns the smallest key, or None
+    /// if Self is empty. If several are equally small, the first one is
+    /// returned. f is called once per element.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let array: [i8; 4] = [-3, 2, -1, 4];
+    /// assert_eq!(Some(&-1), array.min_by_key_const(|e| e.unsigned_abs()));
+    /// ```
+    fn min_by_key_const<
+        K: ~const Ord + ~const Destruct,
+        F: ~const Fn(&T) -> K + ~const Destruct,
+    >(
+        &self,
+        f: F,
+    ) -> Option<&T>;
+
+    /// Binary searches a sorted Self for target, returning the index of
+    /// a matching element or, if there's none, the one where it could be
+    /// inserted keeping the order, like `slice::binary_search`. If several
+    /// elements match, any of them may be returned.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let array: [u8; 5] = [1, 3, 5, 7, 9];
+    /// assert_eq!(Ok(2), array.binary_search(&5));
+    /// assert_eq!(Err(3), array.binary_search(&6));
+    /// ```
+    fn binary_search(&self, target: &T) -> Result<usize, usize>
+    where
+        T: ~const Ord;
+
+    /// Returns the amount of elements for which pred returns true.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let array: [u8; 5] = [0, 1, 0, 2, 0];
+    /// assert_eq!(3, array.count(|e| *e == 0));
+    /// ```
+    fn count<F: ~const Fn(&T) -> bool + ~const Destruct>(&self, pred: F) -> usize;
+
+    /// Returns whether pred returns true for every element, stopping at
+    /// the first one it doesn't. It's true if Self is empty.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let array: [u8; 4] = [2, 4, 6, 8];
+    /// assert!(array.all(|e| e.is_multiple_of(2)));
+    /// assert!([0u8; 0].all(|_| false));
+    /// ```
+    fn all<F: ~const Fn(&T) -> bool + ~const Destruct>(&self, pred: F) -> bool;
+
+    /// Returns whether pred returns true for any element, stopping at
+    /// the first one it does. It's false if Self is empty.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let array: [u8; 4] = [1, 3, 4, 5];
+    /// assert!(array.any(|e| e.is_multiple_of(2)));
+    /// assert!(![0u8; 0].any(|_| true));
+    /// ```
+    fn any<F: ~const Fn(&T) -> bool + ~const Destruct>(&self, pred: F) -> bool;
+
+    /// Returns the indexes of the first CAP elements for which pred
+    /// returns true, in order, alongside the amount of all of them.
+    /// If there are more than CAP, the rest of the indexes are lost but
+    /// still counted, so only `indexes[..count.min(CAP)]` is meaningful,
+    /// the rest of them being 0.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let line: &[u8; 8] = b"a,bc,,d,";
+    /// let (indexes, count) = line.positions::<3, _>(|e| *e == b',');
+    /// assert_eq!(([1, 4, 5], 4), (indexes, count));
+    /// ```
+    fn positions<const CAP: usize, F: ~const Fn(&T) -> bool + ~const Destruct>(
+        &self,
+        pred: F,
+    ) -> ([usize; CAP], usize);
+
+    /// Returns whether f returns true for every pair of elements at the
+    /// same index of Self and other, stopping at the first one it
+    /// doesn't. Both arrays may hold different types.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let array: &[u8; 5] = b"Hello";
+    /// assert!(array.eq_by(b"hELLO", |a, b| a.eq_ignore_ascii_case(b)));
+    /// assert!(array.eq_by(&['H', 'e', 'l', 'l', 'o'], |a, b| *a as char == *b));
+    /// ```
+    fn eq_by<U, F: ~const Fn(&T, &U) -> bool + ~const Destruct>(
+        &self,
+        other: &[U; N],
+        f: F,
+    ) -> bool;
+
+    /// Returns how many leading elements pred returns true for, stopping
+    /// at the first one it doesn't. As the length of an array can't
+    /// depend on its data, slice `&self[..count]` to get them.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let buffer: [u8; 5] = [1, 2, 3, 0, 4];
+    /// let header = buffer.take_while_count(|e| *e != 0);
+    /// assert_eq!([1, 2, 3], buffer[..header]);
+    /// ```
+    fn take_while_count<F: ~const Fn(&T) -> bool + ~const Destruct>(&self, pred: F) -> usize;
+
+    /// Returns how many elements are left after skipping the leading
+    /// ones pred returns true for, so `&self[N - count..]` is the rest.
+    /// It's always `N - self.take_while_count(pred)`.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let buffer: [u8; 5] = [b' ', b' ', b'o', b'k', b' '];
+    /// let rest = buffer.skip_while_count(|e| *e == b' ');
+    /// assert_eq!(b"ok ", &buffer[5 - rest..]);
+    /// ```
+    fn skip_while_count<F: ~const Fn(&T) -> bool + ~const Destruct>(&self, pred: F) -> usize;
+
+    /// Returns whether the first P elements of Self are equal to prefix,
+    /// stopping at the first mismatch. It's always true if P is 0.
+    /// Won't compile if P > N.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let packet: &[u8; 6] = b"GET /x";
+    /// assert!(packet.starts_with(b"GET "));
+    /// assert!(!packet.starts_with(b"PUT "));
+    /// ```
+    fn starts_with<const P: usize>(&self, prefix: &[T; P]) -> bool
+    where
+        T: ~const PartialEq;
+
+    /// Returns whether the last S elements of Self are equal to suffix,
+    /// stopping at the first mismatch. It's always true if S is 0.
+    /// Won't compile if S > N.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let line: &[u8; 5] = b"ok\r\n\0";
+    /// assert!(line.ends_with(b"\n\0"));
+    /// assert!(!line.ends_with(b"\r\0"));
+    /// ```
+    fn ends_with<const S: usize>(&self, suffix: &[T; S]) -> bool
+    where
+        T: ~const PartialEq;
+
+    /// Returns the index of the first occurrence of needle in Self, if
+    /// any. It's a naive search, comparing needle at every offset, which
+    /// is fine for the short markers it's meant for.
+    /// Won't compile if P > N.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let data: &[u8; 8] = b"ab\xff\xd8\xff\xe0cd";
+    /// assert_eq!(Some(2), data.find(&[0xff, 0xd8]));
+    /// assert_eq!(None, data.find(&[0xff, 0xd9]));
+    /// ```
+    fn find<const P: usize>(&self, needle: &[T; P]) -> Option<usize>
+    where
+        T: ~const PartialEq;
+
+    /// Returns the greatest element according to cmp, or None if Self is
+    /// empty. If several are equally great, the last one is returned,
+    /// like `max_const`.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let words: [&str; 3] = ["a", "bcd", "ef"];
+    /// assert_eq!(Some(&"bcd"), words.max_by_const(|a, b| a.len().cmp(&b.len())));
+    /// ```
+    fn max_by_const<F: ~const Fn(&T, &T) -> Ordering + ~const Destruct>(
+        &self,
+        cmp: F,
+    ) -> Option<&T>;
+
+    /// Returns the least element according to cmp, or None if Self is
+    /// empty. If several are equally small, the first one is returned,
+    /// like `min_const`.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let words: [&str; 3] = ["abc", "d", "ef"];
+    /// assert_eq!(Some(&"d"), words.min_by_const(|a, b| a.len().cmp(&b.len())));
+    /// ```
+    fn min_by_const<F: ~const Fn(&T, &T) -> Ordering + ~const Destruct>(
+        &self,
+        cmp: F,
+    ) -> Option<&T>;
+
+    /// Returns the start indexes of the first CAP runs of Self, where a
+    /// new run starts at every element for which same returns false
+    /// when given the previous one, alongside the amount of all runs.
+    /// Like positions, if there are more than CAP runs, the rest of the
+    /// indexes are lost but still counted, so only
+    /// `starts[..count.min(CAP)]` is meaningful.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let array: [u8; 6] = [1, 1, 2, 2, 2, 3];
+    /// let (starts, count) = array.run_boundaries::<4, _>(|a, b| a == b);
+    /// assert_eq!([0, 2, 5], starts[..count]);
+    /// ```
+    fn run_boundaries<const CAP: usize, F: ~const Fn(&T, &T) -> bool + ~const Destruct>(
+        &self,
+        same: F,
+    ) -> ([usize; CAP], usize);
+
+    /// Copies the M elements of Self starting at index start into a new
+    /// array, or returns None if they don't fit, to extract fixed-size
+    /// fields from a larger buffer when start is only known at runtime.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let frame: [u8; 6] = [0xff, 0, 0, 0, 2, 0xaa];
+    /// let len = frame.slice_to_array::<4>(1).map(u32::from_be_bytes);
+    /// assert_eq!(Some(2), len);
+    /// assert_eq!(None, frame.slice_to_array::<4>(3));
+    /// ```
+    fn slice_to_array<const M: usize>(&self, start: usize) -> Option<[T; M]>
+    where
+        T: Copy;
+
+    /// Returns the index of the last element for which pred returns
+    /// true, searching backwards and stopping at the first one it does.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let path: &[u8; 9] = b"/usr/bin/";
+    /// assert_eq!(Some(8), path.rposition(|e| *e == b'/'));
+    /// assert_eq!(None, path.rposition(|e| *e == b'.'));
+    /// ```
+    fn rposition<F: ~const Fn(&T) -> bool + ~const Destruct>(&self, pred: F) -> Option<usize>;
+
+    /// Returns whether every element is less than or equal to the next
+    /// one, which is always true if N <= 1. Meant to check the tables
+    /// given to binary_search at compile time.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// assert!([1, 2, 2, 3].is_sorted());
+    /// assert!(![1, 3, 2].is_sorted());
+    /// ```
+    fn is_sorted(&self) -> bool
+    where
+        T: ~const PartialOrd;
+
+    /// Returns whether f returns true for every pair of consecutive
+    /// elements, stopping at the first one it doesn't. f is given the
+    /// earlier element first. It's always true if N <= 1.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let array: [u8; 3] = [3, 2, 1];
+    /// assert!(array.is_sorted_by(|a, b| a > b));
+    /// ```
+    fn is_sorted_by<F: ~const Fn(&T, &T) -> bool + ~const Destruct>(&self, f: F) -> bool;
+
+    /// Folds every element of Self on its own, starting from init each
+    /// time, returning one accumulator per element. Meant for matrices
+    /// stored as `[[T; C]; R]`, reducing each row independently, like
+    /// their sums or norms.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let matrix: [[u8; 2]; 3] = [[1, 2], [3, 4], [5, 6]];
+    /// let sums = matrix.fold_rows(0, |acc, row| acc + row[0] + row[1]);
+    /// assert_eq!([3, 7, 11], sums);
+    /// ```
+    fn fold_rows<B: Copy, F: ~const FnMut(B, &T) -> B + ~const Destruct>(
+        &self,
+        init: B,
+        f: F,
+    ) -> [B; N];
+
+    /// Returns whether pred returns true for each element, as an array
+    /// of the same length, like a SIMD mask. It can be given to select,
+    /// or used to precompute flags of a table in `const`.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayQuery;
+    ///
+    /// let array: [u8; 4] = [1, 2, 3, 4];
+    /// assert_eq!([false, true, false, true], array.mask(|e| e.is_multiple_of(2)));
+    /// ```
+    fn mask<F: ~const Fn(&T) -> bool + ~const Destruct>(&self, pred: F) -> [bool; N];
+}
+
+/// Holds the methods specific to byte arrays.
+pub trait ArrayBytes<const N: usize> {
+    /// Hashes Self with 64-bit FNV-1a, so it can be used to precompute
+    /// hash tables or dispatch keys in `const` contexts. It's fast but
+    /// not resistant to collision attacks, so don't use it on untrusted
+    /// input where that matters.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayBytes;
+    ///
+    /// let key: &[u8; 6] = b"foobar";
+    /// assert_eq!(0x85944171f73967e8, key.fnv1a());
+    /// ```
+    fn fnv1a(&self) -> u64;
+
+    /// Reads every 4 bytes of Self as a little-endian u32.
+    /// Won't compile if N isn't divisible by 4.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayBytes;
+    ///
+    /// let array: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+    /// assert_eq!([0x04030201, 0x08070605], array.to_u32_array_le());
+    /// ```
+    fn to_u32_array_le(self) -> [u32; N / 4];
+
+    /// Writes every u32 of words as 4 little-endian bytes,
+    /// the inverse of `to_u32_array_le`.
+    /// Won't compile if N isn't divisible by 4.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayBytes;
+    ///
+    /// let array = <[u8; 8]>::from_u32_array_le([0x04030201, 0x08070605]);
+    /// assert_eq!([1, 2, 3, 4, 5, 6, 7, 8], array);
+    /// ```
+    fn from_u32_array_le(words: [u32; N / 4]) -> [u8; N];
+
+    /// Reads every 4 bytes of Self as a big-endian u32.
+    /// Won't compile if N isn't divisible by 4.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayBytes;
+    ///
+    /// let array: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+    /// assert_eq!([0x01020304, 0x05060708], array.to_u32_array_be());
+    /// ```
+    fn to_u32_array_be(self) -> [u32; N / 4];
+
+    /// Writes every u32 of words as 4 big-endian bytes,
+    /// the inverse of `to_u32_array_be`.
+    /// Won't compile if N isn't divisible by 4.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayBytes;
+    ///
+    /// let array = <[u8; 8]>::from_u32_array_be([0x01020304, 0x05060708]);
+    /// assert_eq!([1, 2, 3, 4, 5, 6, 7, 8], array);
+    /// ```
+    fn from_u32_array_be(words: [u32; N / 4]) -> [u8; N];
+
+    /// Returns whether Self has an odd amount of set bits, which is false
+    /// for an empty array. Useful as a simple integrity check that can
+    /// be verified at compile time.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayBytes;
+    ///
+    /// let array: [u8; 2] = [0b0110, 0b1000];
+    /// assert!(array.parity());
+    /// ```
+    fn parity(&self) -> bool;
+
+    /// Adds every byte of Self into an u8, wrapping around on overflow,
+    /// which is 0 for an empty array. Like parity, it's a weak but cheap
+    /// checksum.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayBytes;
+    ///
+    /// let array: [u8; 3] = [0xf0, 0x20, 0x01];
+    /// assert_eq!(0x11, array.wrapping_sum());
+    /// ```
+    fn wrapping_sum(&self) -> u8;
+
+    /// Returns the amount of set bits in Self, treating it as a bitset.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayBytes;
+    ///
+    /// let mask: [u8; 2] = [0xff, 0x0f];
+    /// assert_eq!(12, mask.count_ones());
+    /// ```
+    fn count_ones(&self) -> u32;
+
+    /// Returns whether every bit of Self is unset, which is true for an
+    /// empty array.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayBytes;
+    ///
+    /// assert!([0u8; 4].is_zero());
+    /// assert!(![0, 0, 1, 0].is_zero());
+    /// ```
+    fn is_zero(&self) -> bool;
+}
+
+/// Converts arrays of up to 12 elements into tuples and back,
+/// moving the elements. Useful for destructuring into named bindings
+/// and for interop with APIs that take tuples.
+/// # Examples
+/// ```
+/// use array_manipulation::ToTuple;
+///
+/// let array: [u8; 3] = [1, 2, 3];
+/// let (x, y, z) = array.to_tuple();
+/// assert_eq!((1, 2, 3), (x, y, z));
+/// assert_eq!(array, <[u8; 3]>::from_tuple((x, y, z)));
+/// ```
+pub trait ToTuple: Sized {
+    /// A tuple holding as many elements as the array.
+    type Tuple;
+
+    /// Moves the elements of Self into a tuple, in order.
+    fn to_tuple(self) -> Self::Tuple;
+
+    /// Moves the elements of a tuple into a new array, in order.
+    fn from_tuple(tuple: Self::Tuple) -> Self;
+}
+
+macro_rules! impl_to_tuple {
+    ($($n:literal => ($($i:tt),+)),+ $(,)?) => {
+        $(
+            impl<T> const ToTuple for [T; $n] {
+                type Tuple = ($(impl_to_tuple!(@element $i),)+);
+
+                fn to_tuple(self) -> Self::Tuple {
+                    unsafe {
+                        let ptr = (&raw const self).cast::<T>();
+                        let result = ($(read(ptr.add($i)),)+); // move out every element
+                        forget(self); // avoid drop & deallocation of the moved elements
+                        result
+                    }
+                }
+
+                fn from_tuple(tuple: Self::Tuple) -> Self {
+                    unsafe {
+                        let result = [$(read(&raw const tuple.$i)),+]; // move out every element
+                        forget(tuple); // avoid drop & deallocation of the moved elements
+                        result
+                    }
+                }
+            }
+        )+
+    };
+    (@element $i:tt) => {
+        T
+    };
+}
+
+impl_to_tuple! {
+    1 => (0),
+    2 => (0, 1),
+    3 => (0, 1, 2),
+    4 => (0, 1, 2, 3),
+    5 => (0, 1, 2, 3, 4),
+    6 => (0, 1, 2, 3, 4, 5),
+    7 => (0, 1, 2, 3, 4, 5, 6),
+    8 => (0, 1, 2, 3, 4, 5, 6, 7),
+    9 => (0, 1, 2, 3, 4, 5, 6, 7, 8),
+    10 => (0, 1, 2, 3, 4, 5, 6, 7, 8, 9),
+    11 => (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10),
+    12 => (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11),
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The primitive integers, which `ArrayTransform::cswap` can swap
+/// through a mask, as all their bytes are initialized and they carry
+/// no provenance. Sealed, so it can't be implemented for other types.
+pub trait PrimitiveInt: Copy + sealed::Sealed {
+    /// Returns (other, self) if condition is true or (self, other)
+    /// otherwise, xor-swapping both values through a mask derived from
+    /// condition instead of branching on it.
+    fn masked_swap(self, other: Self, condition: bool) -> (Self, Self);
+}
+
+macro_rules! impl_primitive_int {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl const PrimitiveInt for $t {
+                fn masked_swap(self, other: Self, condition: bool) -> (Self, Self) {
+                    let mask = (condition as $t).wrapping_neg(); // either all zeros or all ones
+                    let diff = (self ^ other) & mask;
+                    (self ^ diff, other ^ diff)
+                }
+            }
+
+            impl const Poison for $t {
+                fn is_poison(&self) -> bool {
+                    size_of::<$t>() > 1 && *self == <$t>::from_ne_bytes([0xfa; size_of::<$t>()])
+                }
+            }
+        )+
+    };
+}
+
+impl_primitive_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Joins any amount of arrays into a single one, in order, through
+/// nested calls to `ArrayAdd::concat`, so the length of the result is
+/// the sum of all of them. It doesn't need `ArrayAdd` to be imported.
+/// # Examples
+/// ```
+/// use array_manipulation::concat_arrays;
+///
+/// let header: [u8; 2] = [0xca, 0xfe];
+/// let result = concat_arrays!(header, [1, 2, 3], [0xff]);
+/// assert_eq!([0xca, 0xfe, 1, 2, 3, 0xff], result);
+/// ```
+#[macro_export]
+macro_rules! concat_arrays {
+    ($array:expr $(,)?) => {
+        $array
+    };
+    ($array:expr, $($rest:expr),+ $(,)?) => {
+        $crate::ArrayAdd::concat($array, $crate::concat_arrays!($($rest),+))
+    };
+}
+
+/// Joins any amount of arrays that overlap by one element with their
+/// neighbours, like the segments of a polyline, through nested calls to
+/// `ArrayAdd::concat_overlap`: each shared boundary element is kept
+/// once, so the result is one element shorter per join.
+/// # Examples
+/// ```
+/// use array_manipulation::join_overlapping;
+///
+/// let a: [(i8, i8); 2] = [(0, 0), (1, 0)];
+/// let result = join_overlapping!(a, [(1, 0), (1, 1)], [(1, 1), (0, 1)]);
+/// assert_eq!([(0, 0), (1, 0), (1, 1), (0, 1)], result);
+/// ```
+#[macro_export]
+macro_rules! join_overlapping {
+    ($array:expr $(,)?) => {
+        $array
+    };
+    ($array:expr, $($rest:expr),+ $(,)?) => {
+        $crate::ArrayAdd::concat_overlap($array, $crate::join_overlapping!($($rest),+))
+    };
+}
+
+/// Fails to compile unless A == B, to require two arrays to have the
+/// same length where the type system can't, like when they hold
+/// different types. It's checked once the generics are known, so
+/// the error only shows up when building, not on `cargo check`,
+/// unless it's called from a `const` item.
+/// # Examples
+/// ```
+/// use array_manipulation::assert_same_len;
+///
+/// fn dot<const N: usize, const M: usize>(a: [i32; N], b: [i8; M]) -> i32 {
+///     assert_same_len::<N, M>();
+///     a.iter().zip(b).map(|(a, b)| a * b as i32).sum()
+/// }
+///
+/// assert_eq!(-1, dot([1, 2, 3], [1, -1, 0]));
+/// ```
+/// ```compile_fail,E0080
+/// use array_manipulation::assert_same_len;
+///
+/// const _: () = assert_same_len::<2, 3>();
+/// ```
+pub const fn assert_same_len<const A: usize, const B: usize>() {
+    let () = Assert::<A, B>::EQ;
+}
+
+/// Generalizes interleave to K channels of M elements, taking the
+/// first element of every channel in order, then the second ones, and
+/// so on, like the frames of multi-channel audio or sensor streams.
+/// The elements are moved, so T doesn't need to be Copy.
+/// # Examples
+/// ```
+/// use array_manipulation::interleave_channels;
+///
+/// let left: [i16; 3] = [1, 2, 3];
+/// let right: [i16; 3] = [-1, -2, -3];
+/// let expected = [1, -1, 2, -2, 3, -3];
+/// let result = interleave_channels([left, right]);
+/// assert_eq!(expected, result);
+/// ```
+pub const fn interleave_channels<T, const K: usize, const M: usize>(
+    channels: [[T; M]; K],
+) -> [T; K * M] {
+    let channels = ManuallyDrop::new(channels);
+    let mut result = poisoned::<[T; K * M]>();
+    let (src, out) = (
+        (&raw const channels).cast::<T>(),
+        result.as_mut_ptr().cast::<T>(),
+    );
+    let mut i = 0;
+    while i < K * M {
+        let (channel, frame) = (i % K, i / K);
+        unsafe { out.add(i).write(read(src.add(channel * M + frame))) }; // move each element into its frame
+        i += 1;
+    }
+    unsafe { assume_filled(result) }
+}
+
+/// Moves the value of every Some of array to the front of a buffer,
+/// keeping their order, compacting a sparse array. As the amount of
+/// them is only known at runtime and a `[T; N]` can't have holes, it's
+/// returned as a ConstVec with capacity N, whose len is that amount.
+/// # Examples
+/// ```
+/// use array_manipulation::flatten_options;
+///
+/// let sparse: [Option<u8>; 3] = [Some(1), None, Some(3)];
+/// let dense = flatten_options(sparse);
+/// assert_eq!(&[1, 3], dense.as_slice());
+/// assert_eq!(Ok([1, 3]), dense.into_array().map_err(|_| ()));
+/// ```
+pub const fn flatten_options<T, const N: usize>(array: [Option<T>; N]) -> ConstVec<T, N> {
+    let array = ManuallyDrop::new(array); // the Nones own nothing to drop
+    let src = (&raw const array).cast::<Option<T>>();
+    let mut result = ConstVec::new();
+    let mut i = 0;
+    while i < N {
+        unsafe {
+            if (*src.add(i)).is_some() {
+                let e = read(src.add(i)).unwrap_unchecked(); // move out the value
+                result.array[result.len] = MaybeUninit::new(e);
+                result.len += 1;
+            }
+        }
+        i += 1;
+    }
+    result
+}
+
+/// Formats byte arrays as hex without allocating, for dumping buffers
+/// on targets without a formatting crate. Needs the `hex` feature.
+/// # Examples
+/// ```
+/// use array_manipulation::ArrayHex;
+///
+/// let array: [u8; 4] = [0xde, 0xad, 0xbe, 0xef];
+/// assert_eq!("deadbeef", array.hex().to_string());
+/// ```
+#[cfg(feature = "hex")]
+pub trait ArrayHex<const N: usize> {
+    /// Writes every byte of Self as two lowercase hex digits into w.
+    fn write_hex<W: core::fmt::Write>(&self, w: &mut W) -> core::fmt::Result;
+
+    /// Wraps Self into a `HexArray`, which implements `Display`
+    /// through `write_hex`.
+    fn hex(&self) -> HexArray<'_, N>;
+}
+
+/// Displays the wrapped byte array as hex, created by `ArrayHex::hex`.
+#[cfg(feature = "hex")]
+#[derive(Clone, Copy, Debug)]
+pub struct HexArray<'a, const N: usize>(pub &'a [u8; N]);
+
+#[cfg(feature = "hex")]
+impl<const N: usize> ArrayHex<N> for [u8; N] {
+    fn write_hex<W: core::fmt::Write>(&self, w: &mut W) -> core::fmt::Result {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+        let mut i = 0;
+        while i < N {
+            w.write_char(DIGITS[(self[i] >> 4) as usize] as char)?;
+            w.write_char(DIGITS[(self[i] & 0xf) as usize] as char)?;
+            i += 1;
+        }
+        Ok(())
+    }
+
+    fn hex(&self) -> HexArray<'_, N> {
+        HexArray(self)
+    }
+}
+
+#[cfg(feature = "hex")]
+impl<const N: usize> core::fmt::Display for HexArray<'_, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.write_hex(f)
+    }
+}
+
+/// Holds the methods over boxed arrays, for arrays too big to be
+/// moved through the stack. Needs the `alloc` feature.
+#[cfg(feature = "alloc")]
+pub trait ArrayBoxed<T, const N: usize> {
+    /// Same as `ArrayAdd::concat`, but joins two boxed arrays into a
+    /// newly allocated one, copying the elements straight from heap to
+    /// heap, so no array is ever placed on the stack.
+    /// # Examples
+    /// ```
+    /// use array_manipulation::ArrayBoxed;
+    ///
+    /// let array: Box<[u8; 4]> = Box::new([1, 2, 3, 4]);
+    /// let expected = Box::new([1, 2, 3, 4, 5, 6, 7]);
+    /// let result = array.concat_boxed(Box::new([5, 6, 7]));
+    /// assert_eq!(expected, result);
+    /// ```
+    fn concat_boxed<const L: usize>(self: Box<Self>, array: Box<[T; L]>) -> Box<[T; N + L]>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> ArrayBoxed<T, N> for [T; N] {
+    fn concat_boxed<const L: usize>(self: Box<Self>, array: Box<[T; L]>) -> Box<[T; N + L]> {
+        let mut result = Box::<[T; N + L]>::new_uninit();
+        unsafe {
+            let out = result.as_mut_ptr().cast::<T>();
+            let (src, array) = (Box::into_raw(self), Box::into_raw(array));
+            copy_nonoverlapping(src.cast::<T>(), out, N);
+            copy_nonoverlapping(array.cast::<T>(), out.add(N), L);
+            // deallocate the inputs without dropping the moved elements
+            drop(Box::from_raw(src.cast::<MaybeUninit<[T; N]>>()));
+            drop(Box::from_raw(array.cast::<MaybeUninit<[T; L]>>()));
+            result.assume_init()
+        }
+    }
+}
+
+// with repr(C) B starts right after A, as the size of an array is always
+// a multiple of its element's alignment, so no padding is ever inserted and
+// the layout matches the one of the resulting array, even for over-aligned T
+#[repr(C)]
+struct Contiguous<A, B>(A, B);
+
+// compile-time checks between const generics, evaluated when monomorphized
+struct Assert<const A: usize, const B: usize>;
+
+impl<const A: usize, const B: usize> Assert<A, B> {
+    const EQ: () = assert!(A == B, "const generics must be equal");
+    const NE: () = assert!(A != B, "const generics must be different");
+    const LT: () = assert!(A < B, "const generic is out of bounds");
+    const DIVIDES: () = assert!(A.is_multiple_of(B), "const generic must be divisible");
+}
+
+// compile-time check of the sum of two const generics against a maximum
+struct AssertSum<const A: usize, const B: usize, const MAX: usize>;
+
+impl<const A: usize, const B: usize, const MAX: usize> AssertSum<A, B, MAX> {
+    const LE: () = assert!(A + B <= MAX, "const generics are out of bounds");
+}
+
+// drops every initialized element of an array with a single moved-out slot,
+// so a panic while the slot is empty doesn't leak nor double-free
+struct HoleGuard<T> {
+    array: *mut T,
+    len: usize,
+    hole: usize,
+}
+
+impl<T> Drop for HoleGuard<T> {
+    fn drop(&mut self) {
+        for i in (0..self.len).filter(|&i| i != self.hole) {
+            unsafe { drop_in_place(self.array.add(i)) }
+        }
+    }
+}
+
+const fn insertion_sort<T: Copy + ~const Ord, const N: usize>(mut array: [T; N]) -> [T; N] {
+    let mut i = 1;
+    while i < N {
+        let e = array[i];
+        let mut j = i;
+        while j > 0 && array[j - 1] > e {
+            array[j] = array[j - 1]; // shift greater elements to the right
+            j -= 1;
+        }
+        array[j] = e;
+        i += 1;
+    }
+    array
+}
+
+const fn conditional_swap<T: ~const PrimitiveInt, const N: usize>(
+    mut array: [T; N],
+    i: usize,
+    j: usize,
+    condition: bool,
+) -> [T; N] {
+    (array[i], array[j]) = array[i].masked_swap(array[j], condition); // a no-op if i == j
+    array
+}
+
+const fn rotate_left<T: Copy, const N: usize>(array: [T; N], k: usize) -> [T; N] {
+    if N == 0 {
+        return array;
+    }
+    let k = k % N;
+    let mut result = poisoned::<[T; N]>();
+    unsafe {
+        let (src, out) = (
+            (&raw const array).cast::<T>(),
+            result.as_mut_ptr().cast::<T>(),
+        );
+        copy_nonoverlapping(src.add(k), out, N - k); // move the tail to the front
+        copy_nonoverlapping(src, out.add(N - k), k); // & the head to the back
+        assume_filled(result)
+    }
+}
+
+const fn dedup<T: Copy + ~const PartialEq, const N: usize>(mut array: [T; N]) -> ([T; N], usize) {
+    if N == 0 {
+        return (array, 0);
+    }
+    let mut count = 1;
+    let mut i = 1;
+    while i < N {
+        if array[i] != array[count - 1] {
+            array[count] = array[i]; // compact new elements into the front
+            count += 1;
+        }
+        i += 1;
+    }
+    (array, count)
+}
+
+const fn scan<T, U: Copy, F: ~const FnMut(U, &T) -> U + ~const Destruct, const N: usize>(
+    array: &[T; N],
+    init: U,
+    mut f: F,
+) -> [U; N] {
+    let mut result = [MaybeUninit::<U>::uninit(); N];
+    let mut acc = init;
+    let mut i = 0;
+    while i < N {
+        acc = f(acc, &array[i]);
+        result[i] = MaybeUninit::new(acc);
+        i += 1;
+    }
+    unsafe { transmute_unchecked(result) }
+}
+
+const fn keep_ends<T, const N: usize, const HEAD: usize, const TAIL: usize>(
+    array: &[T; N],
+) -> [T; HEAD + TAIL] {
+    let () = AssertSum::<HEAD, TAIL, N>::LE; // reject overlapping ends
+    let mut result = poisoned::<[T; HEAD + TAIL]>();
+    unsafe {
+        let (src, out) = (
+            (array as *const [T; N]).cast::<T>(),
+            result.as_mut_ptr().cast::<T>(),
+        );
+        copy_nonoverlapping(src, out, HEAD); // copy the head
+        copy_nonoverlapping(src.add(N - TAIL), out.add(HEAD), TAIL); // & the tail behind it
+        assume_filled(result)
+    }
+}
+
+// drops the already created prefix of an array being built,
+// so a panic while creating the next element doesn't leak
+struct PrefixGuard<T> {
+    array: *mut T,
+    len: usize,
+}
+
+impl<T> Drop for PrefixGuard<T> {
+    fn drop(&mut self) {
+        unsafe { drop_in_place(slice_from_raw_parts_mut(self.array, self.len)) }
+    }
+}
+
+// drops both the already mapped outputs and the inputs not yet moved out
+// while mapping an array, so an early return or a panic doesn't leak
+struct MapGuard<T, U> {
+    src: *mut T,
+    dst: *mut U,
+    len: usize,
+    mapped: usize,
+}
+
+impl<T, U> Drop for MapGuard<T, U> {
+    fn drop(&mut self) {
+        unsafe {
+            // the element at mapped was moved out, but not mapped into dst
+            drop_in_place(slice_from_raw_parts_mut(self.dst, self.mapped));
+            drop_in_place(slice_from_raw_parts_mut(
+                self.src.add(self.mapped + 1),
+                self.len - self.mapped - 1,
+            ));
+        }
+    }
+}
+
+const fn scatter<T: Copy, const N: usize, const M: usize>(
+    mut array: [T; N],
+    indices: [usize; M],
+    values: [T; M],
+) -> [T; N] {
+    let mut i = 0;
+    while i < M {
+        assert!(indices[i] < N, "index out of bounds");
+        array[indices[i]] = values[i];
+        i += 1;
+    }
+    array
+}
+
+const fn enumerate<T, const N: usize>(array: [T; N]) -> [(usize, T); N] {
+    let array = ManuallyDrop::new(array);
+    let mut result = poisoned::<[(usize, T); N]>();
+    let (src, out) = (
+        (&raw const array).cast::<T>(),
+        result.as_mut_ptr().cast::<(usize, T)>(),
+    );
+    let mut i = 0;
+    while i < N {
+        unsafe { out.add(i).write((i, read(src.add(i)))) }; // move each element next to its index
+        i += 1;
+    }
+    unsafe { assume_filled(result) }
+}
+
+const fn copy_within<
+    T: Copy,
+    const N: usize,
+    const SRC: usize,
+    const LEN: usize,
+    const DST: usize,
+>(
+    mut array: [T; N],
+) -> [T; N] {
+    let () = AssertSum::<SRC, LEN, N>::LE; // reject out of bounds ranges
+    let () = AssertSum::<DST, LEN, N>::LE;
+    let ptr = array.as_mut_ptr();
+    unsafe { copy(ptr.add(SRC), ptr.add(DST), LEN) }; // the ranges may overlap
+    array
+}
+
+const fn xor<T: Copy + ~const BitXor<Output = T>, const N: usize>(
+    mut array: [T; N],
+    other: [T; N],
+) -> [T; N] {
+    let mut i = 0;
+    while i < N {
+        array[i] = array[i] ^ other[i];
+        i += 1;
+    }
+    array
+}
+
+const fn and<T: Copy + ~const BitAnd<Output = T>, const N: usize>(
+    mut array: [T; N],
+    other: [T; N],
+) -> [T; N] {
+    let mut i = 0;
+    while i < N {
+        array[i] = array[i] & other[i];
+        i += 1;
+    }
+    array
+}
+
+const fn or<T: Copy + ~const BitOr<Output = T>, const N: usize>(
+    mut array: [T; N],
+    other: [T; N],
+) -> [T; N] {
+    let mut i = 0;
+    while i < N {
+        array[i] = array[i] | other[i];
+        i += 1;
+    }
+    array
+}
+
+const fn compact<T: Copy, F: ~const FnMut(&T) -> bool + ~const Destruct, const N: usize>(
+    mut array: [T; N],
+    mut pred: F,
+) -> ([T; N], usize) {
+    let mut count = 0;
+    let mut i = 0;
+    while i < N {
+        if pred(&array[i]) {
+            array[count] = array[i]; // compact kept elements into the front
+            count += 1;
+        }
+        i += 1;
+    }
+    (array, count)
+}
+
+const fn split_by<T: Copy, F: ~const FnMut(&T) -> bool + ~const Destruct, const N: usize>(
+    array: [T; N],
+    mut pred: F,
+) -> ([T; N], [T; N], usize, usize) {
+    let (mut kept, mut removed) = (array, array);
+    let (mut kept_count, mut removed_count) = (0, 0);
+    let mut i = 0;
+    while i < N {
+        if pred(&array[i]) {
+            kept[kept_count] = array[i];
+            kept_count += 1;
+        } else {
+            removed[removed_count] = array[i];
+            removed_count += 1;
+        }
+        i += 1;
+    }
+    (kept, removed, kept_count, removed_count)
+}
+
+// lomuto partition of array[start..end] around pivot, returning where the
+// elements not smaller than it start
+const fn partition<T: Copy + ~const Ord, const N: usize>(
+    array: &mut [T; N],
+    start: usize,
+    end: usize,
+    pivot: &T,
+) -> usize {
+    let mut split = start;
+    let mut i = start;
+    while i < end {
+        if array[i] < *pivot {
+            (array[split], array[i]) = (array[i], array[split]);
+            split += 1;
+        }
+        i += 1;
+    }
+    split
+}
+
+const fn select_nth<T: Copy + ~const Ord, const N: usize>(
+    mut array: [T; N],
+    k: usize,
+) -> (T, [T; N]) {
+    assert!(k < N, "index out of bounds");
+    let (mut start, mut end) = (0, N);
+    loop {
+        let last = end - 1;
+        (array[start + (end - start) / 2], array[last]) =
+            (array[last], array[start + (end - start) / 2]); // use the middle as pivot
+        let pivot = array[last];
+        let split = partition(&mut array, start, last, &pivot);
+        (array[split], array[last]) = (array[last], array[split]); // & move it between both sides
+        if k == split {
+            return (array[k], array);
+        } else if k < split {
+            end = split;
+        } else {
+            start = split + 1;
+        }
+    }
+}
+
+// storage for a result built through pointer offsets, which in debug builds
+// is filled with 0xfa bytes first, so an element an off-by-one offset never
+// wrote shows up as that pattern instead of leftover stack contents. It's
+// asserted against by assume_filled where that can't give false positives
+const fn poisoned<A>() -> MaybeUninit<A> {
+    let mut storage = MaybeUninit::<A>::uninit();
+    if cfg!(debug_assertions) {
+        unsafe {
+            storage
+                .as_mut_ptr()
+                .cast::<u8>()
+                .write_bytes(0xfa, size_of::<A>())
+        };
+    }
+    storage
+}
+
+// the assume_init of a poisoned result, which in debug builds, while testing
+// or with the poison-check feature, panics if an element still holds the
+// poison. Only the integers wider than a byte are checked, as the padding
+// bytes of other types can't be read, and any byte may be a written value
+const unsafe fn assume_filled<E, const N: usize>(result: MaybeUninit<[E; N]>) -> [E; N] {
+    let result = result.assume_init();
+    if cfg!(all(debug_assertions, any(test, feature = "poison-check"))) {
+        let mut i = 0;
+        while i < N {
+            assert!(!result[i].is_poison(), "element left unwritten");
+            i += 1;
+        }
+    }
+    result
+}
+
+// tells whether a value is the poison of poisoned, for assume_filled
+trait Poison {
+    fn is_poison(&self) -> bool;
+}
+
+impl<T> const Poison for T {
+    default fn is_poison(&self) -> bool {
+        false // not checkable
+    }
+}
+
+const fn replace_at<T: Copy, const N: usize, const P: usize>(
+    mut array: [T; N],
+    at: usize,
+    with: [T; P],
+) -> [T; N] {
+    assert!(P <= N && at <= N - P, "range out of bounds"); // at + P might overflow
+    unsafe { copy_nonoverlapping(with.as_ptr(), array.as_mut_ptr().add(at), P) };
+    array
+}
+
+const fn shift_left<T: Copy, const N: usize, const K: usize>(
+    mut array: [T; N],
+    fill: [T; K],
+) -> ([T; K], [T; N]) {
+    let () = AssertSum::<K, 0, N>::LE; // reject shifting more than N
+    let ptr = array.as_mut_ptr();
+    unsafe {
+        let evicted = read(ptr.cast::<[T; K]>());
+        copy(ptr.add(K), ptr, N - K); // shift the rest to the front
+        copy_nonoverlapping(fill.as_ptr(), ptr.add(N - K), K); // & fill the back
+        (evicted, array)
+    }
+}
+
+const fn matches_at<T: ~const PartialEq, const N: usize, const P: usize>(
+    array: &[T; N],
+    needle: &[T; P],
+    offset: usize,
+) -> bool {
+    let mut i = 0;
+    while i < P {
+        if array[offset + i] != needle[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn cumulative<T: Copy + ~const Ord, const N: usize>(
+    mut array: [T; N],
+    keep: Ordering,
+) -> [T; N] {
+    let mut i = 1;
+    while i < N {
+        if array[i - 1].cmp(&array[i]) == keep {
+            array[i] = array[i - 1]; // carry the extreme so far forward
+        }
+        i += 1;
+    }
+    array
+}
+
+const fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+const fn rotate_left_inplace<T, const N: usize>(array: &mut [T; N], k: usize) {
+    if N == 0 {
+        return;
+    }
+    let k = k % N;
+    let ptr = array.as_mut_ptr();
+    let mut start = 0;
+    while start < gcd(N, k) {
+        // every cycle visits N / gcd(N, k) elements, all starting below gcd(N, k)
+        unsafe {
+            let tmp = read(ptr.add(start)); // open a hole at start
+            let mut hole = start;
+            loop {
+                let next = if hole + k >= N {
+                    hole + k - N
+                } else {
+                    hole + k
+                };
+                if next == start {
+                    break;
+                }
+                copy_nonoverlapping(ptr.add(next), ptr.add(hole), 1); // move the hole forward
+                hole = next;
+            }
+            ptr.add(hole).write(tmp); // & close it with the first element
+        }
+        start += 1;
+    }
+}
+
+const fn reverse_inplace<T, const N: usize>(array: &mut [T; N]) {
+    let ptr = array.as_mut_ptr();
+    let mut i = 0;
+    while i < N / 2 {
+        unsafe { swap(ptr.add(i), ptr.add(N - 1 - i)) }; // the middle one stays put
+        i += 1;
+    }
+}
+
+// from https://github.com/Vurich/const-concat/issues/13#issue-1190857331
+const unsafe fn transmute_unchecked<From, To>(from: From) -> To {
+    union Transmute<From, To> {
+        from: ManuallyDrop<From>,
+        to: ManuallyDrop<To>,
+    }
+
+    ManuallyDrop::into_inner(
+        Transmute {
+            from: ManuallyDrop::new(from),
+        }
+        .to,
+    )
+}
+
+impl<T, const N: usize> const ArrayAdd<T, N> for [T; N] {
+    fn append_many<const M: usize>(self, elems: [T; M]) -> [T; N + M] {
+        self.concat(elems)
+    }
+
+    fn concat<const L: usize>(self, array: [T; L]) -> [T; N + L] {
+        unsafe {
+            // join contiguous memory in a single array
+            transmute_unchecked(Contiguous(self, array))
+        }
+    }
+
+    fn concat_back<const L: usize>(self, array: [T; L]) -> [T; N + L] {
+        unsafe {
+            // join contiguous memory in a single array
+            transmute_unchecked(Contiguous(array, self))
+        }
+    }
+
+    fn concat_ref<const L: usize>(&self, array: &[T; L]) -> [T; N + L]
+    where
+        T: Copy,
+    {
+        unsafe {
+            // join copies of both arrays in a single one
+            transmute_unchecked(Contiguous(*self, *array))
+        }
+    }
+
+    fn concat_const<const L: usize>(self, array: &'static [T; L]) -> [T; N + L]
+    where
+        T: Copy,
+    {
+        unsafe {
+            // join self & a copy of array in a single one
+            transmute_unchecked(Contiguous(self, *array))
+        }
+    }
+
+    fn concat_back_rev<const L: usize>(self, array: [T; L]) -> [T; N + L] {
+        let mut result = poisoned::<[T; N + L]>();
+        unsafe {
+            let (src, out) = (
+                (&raw const array).cast::<T>(),
+                result.as_mut_ptr().cast::<T>(),
+            );
+            let mut i = 0;
+            while i < L {
+                out.add(L - 1 - i).write(read(src.add(i))); // move into descending slots
+                i += 1;
+            }
+            forget(array); // avoid drop & deallocation of the moved elements
+            out.add(L).cast::<[T; N]>().write(self);
+            assume_filled(result)
+        }
+    }
+
+    fn concat_reversed<const L: usize>(self, array: [T; L]) -> [T; N + L] {
+        let mut result = poisoned::<[T; N + L]>();
+        unsafe {
+            let (src, out) = (
+                (&raw const array).cast::<T>(),
+                result.as_mut_ptr().cast::<T>(),
+            );
+            out.cast::<[T; N]>().write(self);
+            let mut i = 0;
+            while i < L {
+                out.add(N + L - 1 - i).write(read(src.add(i))); // move into descending slots
+                i += 1;
+            }
+            forget(array); // avoid drop & deallocation of the moved elements
+            assume_filled(result)
+        }
+    }
+
+    fn concat_into<const L: usize>(
+        self,
+        array: [T; L],
+        out: &mut MaybeUninit<[T; N + L]>,
+    ) -> &mut [T; N + L] {
+        unsafe {
+            // move both arrays into their place of the buffer
+            let ptr = out.as_mut_ptr().cast::<T>();
+            ptr.cast::<[T; N]>().write(self);
+            ptr.add(N).cast::<[T; L]>().write(array);
+            out.assume_init_mut()
+        }
+    }
+
+    fn push_back(self, element: T) -> [T; N + 1] {
+        unsafe {
+            // join contiguous memory in a single array
+            transmute_unchecked(Contiguous(self, element))
+        }
+    }
+
+    fn push_front(self, element: T) -> [T; N + 1] {
+        unsafe {
+            // join contiguous memory in a single array
+            transmute_unchecked(Contiguous(element, self))
+        }
+    }
+
+    default fn interleave(self, other: [T; N]) -> [T; 2 * N] {
+        let mut result = poisoned::<[T; 2 * N]>();
+        unsafe {
+            let (left, right) = (
+                (&raw const self).cast::<T>(),
+                (&raw const other).cast::<T>(),
+            );
+            let out = result.as_mut_ptr().cast::<T>();
+            let mut i = 0;
+            while i < N {
+                // move each pair of elements into alternating slots
+                out.add(2 * i).write(read(left.add(i)));
+                out.add(2 * i + 1).write(read(right.add(i)));
+                i += 1;
+            }
+            forget(self); // avoid drop & deallocation of the moved elements
+            forget(other);
+            assume_filled(result)
+        }
+    }
+
+    fn concat_map<U: ~const Into<T>, const L: usize>(self, array: [U; L]) -> [T; N + L] {
+        let mut array = ManuallyDrop::new(array);
+        let mut result = poisoned::<[T; N + L]>();
+        let out = result.as_mut_ptr().cast::<T>();
+        unsafe { out.cast::<[T; N]>().write(self) };
+        let front = PrefixGuard { array: out, len: N }; // drop the elements of self if a conversion panics
+        let mut guard = MapGuard {
+            src: (&raw mut array).cast::<U>(),
+            dst: unsafe { out.add(N) },
+            len: L,
+            mapped: 0,
+        }; // & both the converted and the unconverted ones of array
+        while guard.mapped < L {
+            unsafe {
+                let e = read(guard.src.add(guard.mapped)); // move out the next element
+                guard.dst.add(guard.mapped).write(e.into()); // & convert it behind self
+            }
+            guard.mapped += 1;
+        }
+        forget(guard);
+        forget(front);
+        unsafe { assume_filled(result) }
+    }
+
+    fn pipe<R, F: ~const FnOnce(Self) -> R + ~const Destruct>(self, f: F) -> R {
+        f(self)
+    }
+
+    fn insert_sorted(self, e: T) -> [T; N + 1]
+    where
+        T: Copy + ~const Ord,
+    {
+        let (mut low, mut high) = (0, N);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self[mid] <= e {
+                low = mid + 1; // skip the elements equal to e too
+            } else {
+                high = mid;
+            }
+        }
+        let mut result = poisoned::<[T; N + 1]>();
+        unsafe {
+            let (src, out) = (self.as_ptr(), result.as_mut_ptr().cast::<T>());
+            copy_nonoverlapping(src, out, low); // copy the head
+            out.add(low).write(e);
+            copy_nonoverlapping(src.add(low), out.add(low + 1), N - low); // & the tail behind e
+            assume_filled(result)
+        }
+    }
+
+    fn reflect_pad<const P: usize>(self) -> [T; N + 2 * P]
+    where
+        T: Copy,
+    {
+        let () = AssertSum::<P, 0, N>::LE; // reject padding longer than self
+        let mut result = [MaybeUninit::<T>::uninit(); N + 2 * P];
+        let mut i = 0;
+        while i < N + 2 * P {
+            let e = if i < P {
+                self[P - 1 - i] // mirror the head
+            } else if i < P + N {
+                self[i - P]
+            } else {
+                self[N - 1 - (i - P - N)] // & the tail
+            };
+            result[i] = MaybeUninit::new(e);
+            i += 1;
+        }
+        unsafe { transmute_unchecked(result) }
+    }
+
+    fn edge_pad<const P: usize>(self) -> [T; N + 2 * P]
+    where
+        T: Copy,
+    {
+        let () = Assert::<N, 0>::NE; // reject arrays without edges
+        let mut result = [MaybeUninit::<T>::uninit(); N + 2 * P];
+        let mut i = 0;
+        while i < N + 2 * P {
+            let e = if i < P {
+                self[0]
+            } else if i < P + N {
+                self[i - P]
+            } else {
+                self[N - 1]
+            };
+            result[i] = MaybeUninit::new(e);
+            i += 1;
+        }
+        unsafe { transmute_unchecked(result) }
+    }
+
+    fn concat_overlap<const L: usize>(self, array: [T; L]) -> [T; N + L - 1]
+    where
+        T: Copy + ~const PartialEq,
+    {
+        let () = Assert::<N, 0>::NE; // reject arrays without a boundary
+        let () = Assert::<L, 0>::NE;
+        debug_assert!(
+            self[N - 1] == array[0],
+            "the boundary elements must be equal"
+        );
+        let mut result = poisoned::<[T; N + L - 1]>();
+        unsafe {
+            let out = result.as_mut_ptr().cast::<T>();
+            copy_nonoverlapping(self.as_ptr(), out, N);
+            copy_nonoverlapping(array.as_ptr().add(1), out.add(N), L - 1); // skip the boundary
+            assume_filled(result)
+        }
+    }
+
+    fn extend_by<const E: usize>(self, filler: [T; E]) -> [T; N + E] {
+        self.concat(filler)
+    }
+
+    fn pad_to<const M: usize>(self, fill: T) -> [T; M]
+    where
+        T: Copy,
+    {
+        let () = AssertSum::<N, 0, M>::LE; // reject targets shorter than self
+        let mut result = [fill; M];
+        unsafe { copy_nonoverlapping(self.as_ptr(), result.as_mut_ptr(), N) };
+        result
+    }
+}
+
+// The Copy specializations never drop anything, which is sound even when
+// one is picked for a type that's only Copy for some lifetimes, as Copy
+// types can't implement Drop nor hold anything that does.
+
+impl<T: Copy, const N: usize> const ArrayAdd<T, N> for [T; N] {
+    fn interleave(self, other: [T; N]) -> [T; 2 * N] {
+        let mut result = [MaybeUninit::<T>::uninit(); 2 * N];
+        let mut i = 0;
+        while i < N {
+            result[2 * i] = MaybeUninit::new(self[i]);
+            result[2 * i + 1] = MaybeUninit::new(other[i]);
+            i += 1;
+        }
+        unsafe { transmute_unchecked(result) }
+    }
+}
+
+impl<T, const N: usize> ArrayAddIter<T, N> for [T; N] {
+    fn append_from_iter<I: IntoIterator<Item = T>, const M: usize>(
+        self,
+        iter: I,
+    ) -> Option<[T; N + M]> {
+        let mut result = poisoned::<[T; N + M]>();
+        let mut guard = PrefixGuard {
+            array: result.as_mut_ptr().cast::<T>(),
+            len: N,
+        }; // drop everything moved in if iter runs out or panics
+        unsafe { guard.array.cast::<[T; N]>().write(self) };
+        let mut iter = iter.into_iter();
+        while guard.len < N + M {
+            unsafe { guard.array.add(guard.len).write(iter.next()?) };
+            guard.len += 1;
+        }
+        forget(guard);
+        unsafe { Some(assume_filled(result)) }
+    }
+}
+
+impl<T, const N: usize> ArrayRemove<T, N> for [T; N] {
+    default fn truncate_start<const L: usize>(mut self) -> [T; N - L] {
+        unsafe {
+            let result = read((&raw const self).cast::<T>().add(L).cast()); // copy from offset'ed pointer
+            drop_in_place(&raw mut self[..L]); // drop popped elements
+            forget(self); // avoid drop & deallocation of the copied elements
+            result
+        }
+    }
+
+    default fn truncate_end<const L: usize>(mut self) -> [T; N - L] {
+        unsafe {
+            drop_in_place(&raw mut self[N - L..]); // drop popped elements
+            transmute_unchecked(self) // resize self
+        }
+    }
+
+    default fn split_first(self) -> (T, [T; N - 1]) {
+        unsafe {
+            let ptr = (&raw const self).cast::<T>();
+            let result = (read(ptr), read(ptr.add(1).cast())); // move out both parts
+            forget(self); // avoid drop & deallocation of the moved elements
+            result
+        }
+    }
+
+    default fn split_last(self) -> (T, [T; N - 1]) {
+        unsafe {
+            let ptr = (&raw const self).cast::<T>();
+            let result = (read(ptr.add(N - 1)), read(ptr.cast())); // move out both parts
+            forget(self); // avoid drop & deallocation of the moved elements
+            result
+        }
+    }
+
+    default fn swap_remove<const IDX: usize>(mut self) -> (T, [T; N - 1]) {
+        let () = Assert::<IDX, N>::LT; // reject out of bounds indexes
+        unsafe {
+            let ptr = (&raw mut self).cast::<T>();
+            let element = read(ptr.add(IDX)); // move out the removed element
+            copy(ptr.add(N - 1), ptr.add(IDX), 1); // fill the gap with the last element
+            (element, transmute_unchecked(self)) // resize self
+        }
+    }
+
+    default fn into_iter_move(self) -> ArrayMoveIter<T, N> {
+        ArrayMoveIter::new(self)
+    }
+
+    default fn keep_ends<const HEAD: usize, const TAIL: usize>(self) -> [T; HEAD + TAIL] {
+        let mut array = ManuallyDrop::new(self);
+        let result = keep_ends(&array); // move out both ends
+        unsafe {
+            let middle = slice_from_raw_parts_mut(array.as_mut_ptr().add(HEAD), N - HEAD - TAIL);
+            drop_in_place(middle); // drop the elements in between
+        }
+        result
+    }
+
+    default fn split_off_start<const L: usize>(self) -> ([T; L], [T; N - L]) {
+        unsafe {
+            let ptr = (&raw const self).cast::<T>();
+            let result = (read(ptr.cast()), read(ptr.add(L).cast())); // move out both parts
+            forget(self); // avoid drop & deallocation of the moved elements
+            result
+        }
+    }
+
+    default fn into_halves(self) -> ([T; N / 2], [T; N - N / 2]) {
+        unsafe {
+            let ptr = (&raw const self).cast::<T>();
+            let result = (read(ptr.cast()), read(ptr.add(N / 2).cast())); // move out both halves
+            forget(self); // avoid drop & deallocation of the moved elements
+            result
+        }
+    }
+
+    default fn into_quarters(self) -> ([T; N / 4], [T; N / 4], [T; N / 4], [T; N - 3 * (N / 4)]) {
+        unsafe {
+            let ptr = (&raw const self).cast::<T>();
+            let result = (
+                read(ptr.cast()),
+                read(ptr.add(N / 4).cast()),
+                read(ptr.add(2 * (N / 4)).cast()),
+                read(ptr.add(3 * (N / 4)).cast()),
+            ); // move out every quarter
+            forget(self); // avoid drop & deallocation of the moved elements
+            result
+        }
+    }
+}
+
+impl<T: Copy, const N: usize> const ArrayRemove<T, N> for [T; N] {
+    fn truncate_start<const L: usize>(self) -> [T; N - L] {
+        unsafe {
+            read((&raw const self).cast::<T>().add(L).cast()) // copy from offset'ed pointer
+        }
+    }
+
+    fn truncate_end<const L: usize>(self) -> [T; N - L] {
+        unsafe {
+            transmute_unchecked(self) // resize self
+        }
+    }
+
+    fn split_first(self) -> (T, [T; N - 1]) {
+        unsafe {
+            let ptr = (&raw const self).cast::<T>();
+            (read(ptr), read(ptr.add(1).cast())) // copy from offset'ed pointers
+        }
+    }
+
+    fn split_last(self) -> (T, [T; N - 1]) {
+        unsafe {
+            let ptr = (&raw const self).cast::<T>();
+            (read(ptr.add(N - 1)), read(ptr.cast())) // copy from offset'ed pointers
+        }
+    }
+
+    fn swap_remove<const IDX: usize>(mut self) -> (T, [T; N - 1]) {
+        let () = Assert::<IDX, N>::LT; // reject out of bounds indexes
+        let element = self[IDX];
+        self[IDX] = self[N - 1]; // fill the gap with the last element
+        unsafe {
+            (element, transmute_unchecked(self)) // resize self
+        }
+    }
+
+    fn into_iter_move(self) -> ArrayMoveIter<T, N> {
+        ArrayMoveIter::new(self)
+    }
+
+    fn keep_ends<const HEAD: usize, const TAIL: usize>(self) -> [T; HEAD + TAIL] {
+        keep_ends(&self)
+    }
+
+    fn split_off_start<const L: usize>(self) -> ([T; L], [T; N - L]) {
+        unsafe {
+            let ptr = (&raw const self).cast::<T>();
+            (read(ptr.cast()), read(ptr.add(L).cast())) // copy from offset'ed pointers
+        }
+    }
+
+    fn into_halves(self) -> ([T; N / 2], [T; N - N / 2]) {
+        unsafe {
+            let ptr = (&raw const self).cast::<T>();
+            (read(ptr.cast()), read(ptr.add(N / 2).cast())) // copy from offset'ed pointers
+        }
+    }
+
+    fn into_quarters(self) -> ([T; N / 4], [T; N / 4], [T; N / 4], [T; N - 3 * (N / 4)]) {
+        unsafe {
+            let ptr = (&raw const self).cast::<T>();
+            (
+                read(ptr.cast()),
+                read(ptr.add(N / 4).cast()),
+                read(ptr.add(2 * (N / 4)).cast()),
+                read(ptr.add(3 * (N / 4)).cast()),
+            ) // copy from offset'ed pointers
+        }
+    }
+}
+
+impl<T, const N: usize> const ArraySplit<T, N> for [T; N] {
+    fn windows<const W: usize>(self) -> [[T; W]; N - W + 1]
+    where
+        T: Copy,
+    {
+        let () = Assert::<W, 0>::NE; // reject empty windows
+        let mut result = poisoned::<[[T; W]; N - W + 1]>();
+        let mut i = 0;
+        while i < N - W + 1 {
+            unsafe {
+                // copy W elements from offset'ed pointer into the i-th window
+                let window = read((&raw const self).cast::<T>().add(i).cast::<[T; W]>());
+                result.as_mut_ptr().cast::<[T; W]>().add(i).write(window);
+            }
+            i += 1;
+        }
+        unsafe { assume_filled(result) }
+    }
+
+    fn windows_map<const W: usize, U, F: ~const FnMut(&[T; W]) -> U + ~const Destruct>(
+        self,
+        mut f: F,
+    ) -> [U; N - W + 1]
+    where
+        T: Copy,
+    {
+        let () = Assert::<W, 0>::NE; // reject empty windows
+        let mut result = poisoned::<[U; N - W + 1]>();
+        let (src, out) = (
+            (&raw const self).cast::<T>(),
+            result.as_mut_ptr().cast::<U>(),
+        );
+        let mut i = 0;
+        while i < N - W + 1 {
+            unsafe { out.add(i).write(f(&*src.add(i).cast::<[T; W]>())) } // view the i-th window
+            i += 1;
+        }
+        unsafe { assume_filled(result) }
+    }
+
+    fn deinterleave(self) -> ([T; N / 2], [T; N / 2]) {
+        let () = Assert::<N, 2>::DIVIDES; // reject odd lengths
+        let mut even = poisoned::<[T; N / 2]>();
+        let mut odd = poisoned::<[T; N / 2]>();
+        unsafe {
+            let ptr = (&raw const self).cast::<T>();
+            let (even_ptr, odd_ptr) = (even.as_mut_ptr().cast::<T>(), odd.as_mut_ptr().cast::<T>());
+            let mut i = 0;
+            while i < N / 2 {
+                // move alternating elements into their own array
+                even_ptr.add(i).write(read(ptr.add(2 * i)));
+                odd_ptr.add(i).write(read(ptr.add(2 * i + 1)));
+                i += 1;
+            }
+            forget(self); // avoid drop & deallocation of the moved elements
+            (assume_filled(even), assume_filled(odd))
+        }
+    }
+
+    fn for_each_chunk<const C: usize, F: ~const FnMut(&mut [T; C]) + ~const Destruct>(
+        &mut self,
+        mut f: F,
+    ) {
+        let () = Assert::<N, C>::DIVIDES; // reject incomplete chunks
+        let ptr = (self as *mut [T; N]).cast::<[T; C]>();
+        let mut i = 0;
+        while i < N / C {
+            unsafe { f(&mut *ptr.add(i)) } // reinterpret the i-th chunk
+            i += 1;
+        }
+    }
+
+    fn split_ref<const M: usize>(&self) -> (&[T; M], &[T; N - M]) {
+        let ptr = (self as *const [T; N]).cast::<T>();
+        unsafe {
+            // reinterpret both halves of the storage
+            (&*ptr.cast(), &*ptr.add(M).cast())
+        }
+    }
+
+    fn split_mut<const M: usize>(&mut self) -> (&mut [T; M], &mut [T; N - M]) {
+        let ptr = (self as *mut [T; N]).cast::<T>();
+        unsafe {
+            // reinterpret both halves of the storage, which don't overlap
+            (&mut *ptr.cast(), &mut *ptr.add(M).cast())
+        }
+    }
+
+    fn group_pairs(self) -> [[T; 2]; N / 2] {
+        let () = Assert::<N, 2>::DIVIDES; // reject odd lengths
+        unsafe {
+            transmute_unchecked(self) // [T; N] has the same layout as [[T; 2]; N / 2]
+        }
+    }
+
+    fn rchunks<const C: usize>(self) -> [[T; C]; N / C] {
+        let () = Assert::<N, C>::DIVIDES; // reject incomplete chunks
+        let mut result = poisoned::<[[T; C]; N / C]>();
+        unsafe {
+            let (src, out) = (
+                (&raw const self).cast::<[T; C]>(),
+                result.as_mut_ptr().cast::<[T; C]>(),
+            );
+            let mut i = 0;
+            while i < N / C {
+                out.add(i).write(read(src.add(N / C - 1 - i))); // move the chunks in reverse
+                i += 1;
+            }
+            forget(self); // avoid drop & deallocation of the moved elements
+            assume_filled(result)
+        }
+    }
+
+    fn chunks_with_remainder<const C: usize>(self) -> ([[T; C]; N / C], [T; N % C]) {
+        unsafe {
+            let ptr = (&raw const self).cast::<T>();
+            // the chunks & the remainder are contiguous in the storage
+            let result = (read(ptr.cast()), read(ptr.add(N - N % C).cast()));
+            forget(self); // avoid drop & deallocation of the moved elements
+            result
+        }
+    }
+
+    fn fold_chunks<const C: usize, B, F: ~const FnMut(B, &[T; C]) -> B + ~const Destruct>(
+        self,
+        init: B,
+        mut f: F,
+    ) -> B
+    where
+        T: Copy,
+    {
+        let () = Assert::<N, C>::DIVIDES; // reject incomplete chunks
+        let ptr = (&raw const self).cast::<[T; C]>();
+        let mut acc = init;
+        let mut i = 0;
+        while i < N / C {
+            acc = f(acc, unsafe { &*ptr.add(i) }); // reinterpret the i-th chunk
+            i += 1;
+        }
+        acc
+    }
+
+    fn map_chunks<const C: usize, U, F: ~const FnMut([T; C]) -> [U; C] + ~const Destruct>(
+        self,
+        mut f: F,
+    ) -> [U; C * (N / C)] {
+        let () = Assert::<N, C>::DIVIDES; // reject incomplete chunks
+        let mut array = ManuallyDrop::new(self);
+        let mut result = poisoned::<[U; C * (N / C)]>();
+        let mut guard = MapGuard {
+            src: (&raw mut array).cast::<[T; C]>(),
+            dst: result.as_mut_ptr().cast::<[U; C]>(),
+            len: N / C,
+            mapped: 0,
+        }; // drop every chunk left if f panics
+        while guard.mapped < N / C {
+            unsafe {
+                let chunk = read(guard.src.add(guard.mapped)); // move out the next chunk
+                guard.dst.add(guard.mapped).write(f(chunk));
+            }
+            guard.mapped += 1;
+        }
+        forget(guard);
+        unsafe { assume_filled(result) }
+    }
+}
+
+impl<T, const N: usize> const ArrayInit<T, N> for [T; N] {
+    fn fill(e: T) -> [T; N]
+    where
+        T: Copy,
+    {
+        let mut result = [MaybeUninit::<T>::uninit(); N];
+        let mut i = 0;
+        while i < N {
+            result[i] = MaybeUninit::new(e);
+            i += 1;
+        }
+        unsafe { transmute_unchecked(result) }
+    }
+
+    fn from_slice(slice: &[T]) -> Option<[T; N]>
+    where
+        T: Copy,
+    {
+        if slice.len() != N {
+            return None;
+        }
+        unsafe { Some(read(slice.as_ptr().cast())) } // copy the whole slice
+    }
+
+    fn from_slice_or<F: ~const FnMut(usize) -> T + ~const Destruct>(
+        slice: &[T],
+        mut f: F,
+    ) -> Option<[T; N]>
+    where
+        T: Copy,
+    {
+        if slice.len() > N {
+            return None;
+        }
+        let mut result = [MaybeUninit::<T>::uninit(); N];
+        let mut i = 0;
+        while i < N {
+            result[i] = MaybeUninit::new(if i < slice.len() { slice[i] } else { f(i) });
+            i += 1;
+        }
+        unsafe { Some(transmute_unchecked(result)) }
+    }
+
+    fn build<F: ~const FnMut(usize) -> T + ~const Destruct>(mut f: F) -> [T; N] {
+        let mut result = poisoned::<[T; N]>();
+        let mut guard = PrefixGuard {
+            array: result.as_mut_ptr().cast::<T>(),
+            len: 0,
+        }; // drop the created elements if f panics
+        while guard.len < N {
+            unsafe { guard.array.add(guard.len).write(f(guard.len)) };
+            guard.len += 1;
+        }
+        forget(guard);
+        unsafe { assume_filled(result) }
+    }
+
+    fn try_build<E: ~const Destruct, F: ~const FnMut(usize) -> Result<T, E> + ~const Destruct>(
+        mut f: F,
+    ) -> Result<[T; N], E>
+    where
+        T: ~const Destruct,
+    {
+        let mut result = poisoned::<[T; N]>();
+        let mut guard = PrefixGuard {
+            array: result.as_mut_ptr().cast::<T>(),
+            len: 0,
+        }; // drop the created elements if f panics
+        while guard.len < N {
+            match f(guard.len) {
+                Ok(e) => unsafe { guard.array.add(guard.len).write(e) },
+                Err(e) => {
+                    let created = slice_from_raw_parts_mut(guard.array, guard.len);
+                    forget(guard); // the guard's drop can't run in const contexts
+                    unsafe { drop_in_place(created) };
+                    return Err(e);
+                }
+            }
+            guard.len += 1;
+        }
+        forget(guard);
+        unsafe { Ok(assume_filled(result)) }
+    }
+}
+
+impl<T, const N: usize> ArrayTransform<T, N> for [T; N] {
+    default fn update_at<const IDX: usize, F: FnOnce(T) -> T>(self, f: F) -> [T; N] {
+        let () = Assert::<IDX, N>::LT; // reject out of bounds indexes
+        let mut array = ManuallyDrop::new(self);
+        unsafe {
+            let slot = array.as_mut_ptr().add(IDX);
+            let guard = HoleGuard {
+                array: array.as_mut_ptr(),
+                len: N,
+                hole: IDX,
+            }; // drop the rest of the elements if f panics
+            slot.write(f(read(slot))); // move the element out & back in
+            forget(guard);
+        }
+        ManuallyDrop::into_inner(array)
+    }
+
+    default fn sort(self) -> [T; N]
+    where
+        T: Copy + Ord,
+    {
+        insertion_sort(self)
+    }
+
+    default fn get_and_replace(mut self, idx: usize, e: T) -> Result<([T; N], T), ([T; N], T)> {
+        if idx < N {
+            let old = replace(&mut self[idx], e);
+            Ok((self, old))
+        } else {
+            Err((self, e))
+        }
+    }
+
+    default fn cswap<const I: usize, const J: usize>(self, condition: bool) -> [T; N]
+    where
+        T: PrimitiveInt,
+    {
+        let () = Assert::<I, N>::LT; // reject out of bounds indexes
+        let () = Assert::<J, N>::LT;
+        conditional_swap(self, I, J, condition)
+    }
+
+    default fn rotate_left_by(self, k: usize) -> [T; N]
+    where
+        T: Copy,
+    {
+        rotate_left(self, k)
+    }
+
+    default fn dedup_consecutive(self) -> ([T; N], usize)
+    where
+        T: Copy + PartialEq,
+    {
+        dedup(self)
+    }
+
+    default fn scan_const<U: Copy, F: FnMut(U, &T) -> U>(self, init: U, f: F) -> [U; N] {
+        scan(&self, init, f)
+    }
+
+    default fn try_map_each<U, E, F: FnMut(T) -> Result<U, E>>(
+        self,
+        mut f: F,
+    ) -> Result<[U; N], E> {
+        let mut array = ManuallyDrop::new(self);
+        let mut result = poisoned::<[U; N]>();
+        let mut guard = MapGuard {
+            src: array.as_mut_ptr(),
+            dst: result.as_mut_ptr().cast::<U>(),
+            len: N,
+            mapped: 0,
+        }; // drop everything left if f fails or panics
+        while guard.mapped < N {
+            unsafe {
+                let e = read(guard.src.add(guard.mapped)); // move out the next element
+                guard.dst.add(guard.mapped).write(f(e)?);
+            }
+            guard.mapped += 1;
+        }
+        forget(guard);
+        unsafe { Ok(assume_filled(result)) }
+    }
+
+    default fn scatter<const M: usize>(self, indices: [usize; M], values: [T; M]) -> [T; N]
+    where
+        T: Copy,
+    {
+        scatter(self, indices, values)
+    }
+
+    default fn enumerate_const(self) -> [(usize, T); N] {
+        enumerate(self)
+    }
+
+    default fn fold_const<B, F: FnMut(B, T) -> B>(self, init: B, f: F) -> B {
+        ArrayMoveIter::new(self).fold(init, f) // drops the unconsumed tail if f panics
+    }
+
+    default fn reduce_const<F: FnMut(T, T) -> T>(self, f: F) -> Option<T> {
+        ArrayMoveIter::new(self).reduce(f)
+    }
+
+    default fn copy_within_const<const SRC: usize, const LEN: usize, const DST: usize>(
+        self,
+    ) -> [T; N]
+    where
+        T: Copy,
+    {
+        copy_within::<T, N, SRC, LEN, DST>(self)
+    }
+
+    default fn xor(self, other: [T; N]) -> [T; N]
+    where
+        T: Copy + BitXor<Output = T>,
+    {
+        xor(self, other)
+    }
+
+    default fn and(self, other: [T; N]) -> [T; N]
+    where
+        T: Copy + BitAnd<Output = T>,
+    {
+        and(self, other)
+    }
+
+    default fn or(self, other: [T; N]) -> [T; N]
+    where
+        T: Copy + BitOr<Output = T>,
+    {
+        or(self, other)
+    }
+
+    default fn compact<F: FnMut(&T) -> bool>(self, pred: F) -> ([T; N], usize)
+    where
+        T: Copy,
+    {
+        compact(self, pred)
+    }
+
+    default fn rotate_to_front(self, head: usize) -> [T; N]
+    where
+        T: Copy,
+    {
+        rotate_left(self, head)
+    }
+
+    default fn split_by<F: FnMut(&T) -> bool>(self, pred: F) -> ([T; N], [T; N], usize, usize)
+    where
+        T: Copy,
+    {
+        split_by(self, pred)
+    }
+
+    default fn map_each<F: FnMut(T) -> T>(self, f: F) -> [T; N] {
+        self.map(f) // already drops everything left if f panics
+    }
+
+    default fn partition_around(mut self, pivot: T) -> ([T; N], usize)
+    where
+        T: Copy + Ord,
+    {
+        let split = partition(&mut self, 0, N, &pivot);
+        (self, split)
+    }
+
+    default fn select_nth(self, k: usize) -> (T, [T; N])
+    where
+        T: Copy + Ord,
+    {
+        select_nth(self, k)
+    }
+
+    default fn rotate_left_inplace<const K: usize>(&mut self) {
+        rotate_left_inplace(self, K)
+    }
+
+    default fn dedup_sorted(self) -> ([T; N], usize)
+    where
+        T: Copy + PartialEq,
+    {
+        dedup(self)
+    }
+
+    default fn cummax(self) -> [T; N]
+    where
+        T: Copy + Ord,
+    {
+        cumulative(self, Ordering::Greater)
+    }
+
+    default fn cummin(self) -> [T; N]
+    where
+        T: Copy + Ord,
+    {
+        cumulative(self, Ordering::Less)
+    }
+
+    default fn replace<const P: usize>(self, at: usize, with: [T; P]) -> [T; N]
+    where
+        T: Copy,
+    {
+        replace_at(self, at, with)
+    }
+
+    default fn shift_left<const K: usize>(self, fill: [T; K]) -> ([T; K], [T; N])
+    where
+        T: Copy,
+    {
+        shift_left(self, fill)
+    }
+
+    default fn reverse_inplace(&mut self) {
+        reverse_inplace(self)
+    }
+
+    default fn select(self, other: [T; N], mask: [bool; N]) -> [T; N] {
+        let mut other = other.into_iter().zip(mask);
+        self.map(|e| match other.next() {
+            Some((_, true)) => e,
+            Some((o, false)) => o,
+            None => unreachable!(), // both have N elements
+        }) // the element not taken is dropped on every step
+    }
+}
+
+impl<T: Copy, const N: usize> const ArrayTransform<T, N> for [T; N] {
+    fn update_at<const IDX: usize, F: ~const FnOnce(T) -> T + ~const Destruct>(
+        mut self,
+        f: F,
+    ) -> [T; N] {
+        let () = Assert::<IDX, N>::LT; // reject out of bounds indexes
+        self[IDX] = f(self[IDX]);
+        self
+    }
+
+    fn sort(self) -> [T; N]
+    where
+        T: Copy + ~const Ord,
+    {
+        insertion_sort(self)
+    }
+
+    fn get_and_replace(mut self, idx: usize, e: T) -> Result<([T; N], T), ([T; N], T)> {
+        if idx < N {
+            let old = self[idx];
+            self[idx] = e;
+            Ok((self, old))
+        } else {
+            Err((self, e))
+        }
+    }
+
+    fn cswap<const I: usize, const J: usize>(self, condition: bool) -> [T; N]
+    where
+        T: ~const PrimitiveInt,
+    {
+        let () = Assert::<I, N>::LT; // reject out of bounds indexes
+        let () = Assert::<J, N>::LT;
+        conditional_swap(self, I, J, condition)
+    }
+
+    fn rotate_left_by(self, k: usize) -> [T; N]
+    where
+        T: Copy,
+    {
+        rotate_left(self, k)
+    }
+
+    fn dedup_consecutive(self) -> ([T; N], usize)
+    where
+        T: Copy + ~const PartialEq,
+    {
+        dedup(self)
+    }
+
+    fn scan_const<U: Copy, F: ~const FnMut(U, &T) -> U + ~const Destruct>(
+        self,
+        init: U,
+        f: F,
+    ) -> [U; N] {
+        scan(&self, init, f)
+    }
+
+    fn try_map_each<
+        U: ~const Destruct,
+        E: ~const Destruct,
+        F: ~const FnMut(T) -> Result<U, E> + ~const Destruct,
+    >(
+        self,
+        mut f: F,
+    ) -> Result<[U; N], E> {
+        let mut result = poisoned::<[U; N]>();
+        let mut guard = PrefixGuard {
+            array: result.as_mut_ptr().cast::<U>(),
+            len: 0,
+        }; // drop the converted elements if f panics
+        while guard.len < N {
+            match f(self[guard.len]) {
+                Ok(e) => unsafe { guard.array.add(guard.len).write(e) },
+                Err(e) => {
+                    let converted = slice_from_raw_parts_mut(guard.array, guard.len);
+                    forget(guard); // the guard's drop can't run in const contexts
+                    unsafe { drop_in_place(converted) };
+                    return Err(e);
+                }
+            }
+            guard.len += 1;
+        }
+        forget(guard);
+        unsafe { Ok(assume_filled(result)) }
+    }
+
+    fn scatter<const M: usize>(self, indices: [usize; M], values: [T; M]) -> [T; N]
+    where
+        T: Copy,
+    {
+        scatter(self, indices, values)
+    }
+
+    fn enumerate_const(self) -> [(usize, T); N] {
+        enumerate(self)
+    }
+
+    fn fold_const<B, F: ~const FnMut(B, T) -> B + ~const Destruct>(self, init: B, mut f: F) -> B {
+        let mut acc = init;
+        let mut i = 0;
+        while i < N {
+            acc = f(acc, self[i]);
+            i += 1;
+        }
+        acc
+    }
+
+    fn reduce_const<F: ~const FnMut(T, T) -> T + ~const Destruct>(self, mut f: F) -> Option<T> {
+        if N == 0 {
+            return None;
+        }
+        let (mut acc, mut i) = (self[0], 1);
+        while i < N {
+            acc = f(acc, self[i]);
+            i += 1;
+        }
+        Some(acc)
+    }
+
+    fn copy_within_const<const SRC: usize, const LEN: usize, const DST: usize>(self) -> [T; N]
+    where
+        T: Copy,
+    {
+        copy_within::<T, N, SRC, LEN, DST>(self)
+    }
+
+    fn xor(self, other: [T; N]) -> [T; N]
+    where
+        T: Copy + ~const BitXor<Output = T>,
+    {
+        xor(self, other)
+    }
+
+    fn and(self, other: [T; N]) -> [T; N]
+    where
+        T: Copy + ~const BitAnd<Output = T>,
+    {
+        and(self, other)
+    }
+
+    fn or(self, other: [T; N]) -> [T; N]
+    where
+        T: Copy + ~const BitOr<Output = T>,
+    {
+        or(self, other)
+    }
+
+    fn compact<F: ~const FnMut(&T) -> bool + ~const Destruct>(self, pred: F) -> ([T; N], usize)
+    where
+        T: Copy,
+    {
+        compact(self, pred)
+    }
+
+    fn rotate_to_front(self, head: usize) -> [T; N]
+    where
+        T: Copy,
+    {
+        rotate_left(self, head)
+    }
+
+    fn split_by<F: ~const FnMut(&T) -> bool + ~const Destruct>(
+        self,
+        pred: F,
+    ) -> ([T; N], [T; N], usize, usize)
+    where
+        T: Copy,
+    {
+        split_by(self, pred)
+    }
+
+    fn map_each<F: ~const FnMut(T) -> T + ~const Destruct>(mut self, mut f: F) -> [T; N] {
+        let mut i = 0;
+        while i < N {
+            self[i] = f(self[i]);
+            i += 1;
+        }
+        self
+    }
+
+    fn partition_around(mut self, pivot: T) -> ([T; N], usize)
+    where
+        T: Copy + ~const Ord,
+    {
+        let split = partition(&mut self, 0, N, &pivot);
+        (self, split)
+    }
+
+    fn select_nth(self, k: usize) -> (T, [T; N])
+    where
+        T: Copy + ~const Ord,
+    {
+        select_nth(self, k)
+    }
+
+    fn rotate_left_inplace<const K: usize>(&mut self) {
+        rotate_left_inplace(self, K)
+    }
+
+    fn dedup_sorted(self) -> ([T; N], usize)
+    where
+        T: Copy + ~const PartialEq,
+    {
+        dedup(self)
+    }
+
+    fn cummax(self) -> [T; N]
+    where
+        T: Copy + ~const Ord,
+    {
+        cumulative(self, Ordering::Greater)
+    }
+
+    fn cummin(self) -> [T; N]
+    where
+        T: Copy + ~const Ord,
+    {
+        cumulative(self, Ordering::Less)
+    }
+
+    fn replace<const P: usize>(self, at: usize, with: [T; P]) -> [T; N]
+    where
+        T: Copy,
+    {
+        replace_at(self, at, with)
+    }
+
+    fn shift_left<const K: usize>(self, fill: [T; K]) -> ([T; K], [T; N])
+    where
+        T: Copy,
+    {
+        shift_left(self, fill)
+    }
+
+    fn reverse_inplace(&mut self) {
+        reverse_inplace(self)
+    }
+
+    fn select(mut self, other: [T; N], mask: [bool; N]) -> [T; N] {
+        let mut i = 0;
+        while i < N {
+            self[i] = [other[i], self[i]][mask[i] as usize]; // index instead of branching
+            i += 1;
+        }
+        self
+    }
+}
+
+impl<T, const N: usize> const ArrayQuery<T, N> for [T; N] {
+    fn position(&self, target: &T) -> Option<usize>
+    where
+        T: ~const PartialEq,
+    {
+        let mut i = 0;
+        while i < N {
+            if self[i] == *target {
+                return Some(i);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    fn contains(&self, target: &T) -> bool
+    where
+        T: ~const PartialEq,
+    {
+        self.position(target).is_some()
+    }
+
+    fn partition_point<F: ~const Fn(&T) -> bool + ~const Destruct>(&self, pred: F) -> usize {
+        let (mut low, mut high) = (0, N);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if pred(&self[mid]) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
+
+    fn gather<const M: usize>(&self, indices: [usize; M]) -> [T; M]
+    where
+        T: Copy,
+    {
+        let mut result = [MaybeUninit::<T>::uninit(); M];
+        let mut i = 0;
+        while i < M {
+            assert!(indices[i] < N, "index out of bounds");
+            result[i] = MaybeUninit::new(self[indices[i]]);
+            i += 1;
+        }
+        unsafe { transmute_unchecked(result) }
+    }
+
+    fn argsort(&self) -> [usize; N]
+    where
+        T: ~const Ord,
+    {
+        let mut indices = [0; N];
+        let mut i = 0;
+        while i < N {
+            indices[i] = i;
+            i += 1;
+        }
+        i = 1;
+        while i < N {
+            let idx = indices[i];
+            let mut j = i;
+            while j > 0 && self[indices[j - 1]] > self[idx] {
+                indices[j] = indices[j - 1]; // shift indexes of greater elements to the right
+                j -= 1;
+            }
+            indices[j] = idx;
+            i += 1;
+        }
+        indices
+    }
+
+    fn min_const(&self) -> Option<&T>
+    where
+        T: ~const Ord,
+    {
+        if N == 0 {
+            return None;
+        }
+        let (mut min, mut i) = (0, 1);
+        while i < N {
+            if self[i] < self[min] {
+                min = i;
+            }
+            i += 1;
+        }
+        Some(&self[min])
+    }
+
+    fn max_const(&self) -> Option<&T>
+    where
+        T: ~const Ord,
+    {
+        if N == 0 {
+            return None;
+        }
+        let (mut max, mut i) = (0, 1);
+        while i < N {
+            if self[i] >= self[max] {
+                max = i;
+            }
+            i += 1;
+        }
+        Some(&self[max])
+    }
+
+    fn min_by_key_const<
+        K: ~const Ord + ~const Destruct,
+        F: ~const Fn(&T) -> K + ~const Destruct,
+    >(
+        &self,
+        f: F,
+    ) -> Option<&T> {
+        if N == 0 {
+            return None;
+        }
+        let (mut min, mut min_key, mut i) = (0, f(&self[0]), 1);
+        while i < N {
+            let key = f(&self[i]);
+            if key < min_key {
+                (min, min_key) = (i, key);
+            }
+            i += 1;
+        }
+        Some(&self[min])
+    }
+
+    fn binary_search(&self, target: &T) -> Result<usize, usize>
+    where
+        T: ~const Ord,
+    {
+        let (mut low, mut high) = (0, N);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match self[mid].cmp(target) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(low)
+    }
+
+    fn count<F: ~const Fn(&T) -> bool + ~const Destruct>(&self, pred: F) -> usize {
+        let mut count = 0;
+        let mut i = 0;
+        while i < N {
+            if pred(&self[i]) {
+                count += 1;
+            }
+            i += 1;
+        }
+        count
+    }
+
+    fn all<F: ~const Fn(&T) -> bool + ~const Destruct>(&self, pred: F) -> bool {
+        let mut i = 0;
+        while i < N {
+            if !pred(&self[i]) {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    fn any<F: ~const Fn(&T) -> bool + ~const Destruct>(&self, pred: F) -> bool {
+        let mut i = 0;
+        while i < N {
+            if pred(&self[i]) {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    fn positions<const CAP: usize, F: ~const Fn(&T) -> bool + ~const Destruct>(
+        &self,
+        pred: F,
+    ) -> ([usize; CAP], usize) {
+        let mut indexes = [0; CAP];
+        let mut count = 0;
+        let mut i = 0;
+        while i < N {
+            if pred(&self[i]) {
+                if count < CAP {
+                    indexes[count] = i;
+                }
+                count += 1; // keep counting past the capacity
+            }
+            i += 1;
+        }
+        (indexes, count)
+    }
+
+    fn eq_by<U, F: ~const Fn(&T, &U) -> bool + ~const Destruct>(
+        &self,
+        other: &[U; N],
+        f: F,
+    ) -> bool {
+        let mut i = 0;
+        while i < N {
+            if !f(&self[i], &other[i]) {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    fn take_while_count<F: ~const Fn(&T) -> bool + ~const Destruct>(&self, pred: F) -> usize {
+        let mut i = 0;
+        while i < N && pred(&self[i]) {
+            i += 1;
+        }
+        i
+    }
+
+    fn skip_while_count<F: ~const Fn(&T) -> bool + ~const Destruct>(&self, pred: F) -> usize {
+        N - self.take_while_count(pred)
+    }
+
+    fn starts_with<const P: usize>(&self, prefix: &[T; P]) -> bool
+    where
+        T: ~const PartialEq,
+    {
+        let () = AssertSum::<P, 0, N>::LE; // reject prefixes longer than self
+        matches_at(self, prefix, 0)
+    }
+
+    fn ends_with<const S: usize>(&self, suffix: &[T; S]) -> bool
+    where
+        T: ~const PartialEq,
+    {
+        let () = AssertSum::<S, 0, N>::LE; // reject suffixes longer than self
+        matches_at(self, suffix, N - S)
+    }
+
+    fn find<const P: usize>(&self, needle: &[T; P]) -> Option<usize>
+    where
+        T: ~const PartialEq,
+    {
+        let () = AssertSum::<P, 0, N>::LE; // reject needles longer than self
+        let mut i = 0;
+        while i <= N - P {
+            if matches_at(self, needle, i) {
+                return Some(i);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    fn max_by_const<F: ~const Fn(&T, &T) -> Ordering + ~const Destruct>(
+        &self,
+        cmp: F,
+    ) -> Option<&T> {
+        if N == 0 {
+            return None;
+        }
+        let (mut max, mut i) = (0, 1);
+        while i < N {
+            if cmp(&self[i], &self[max]) != Ordering::Less {
+                max = i;
+            }
+            i += 1;
+        }
+        Some(&self[max])
+    }
+
+    fn min_by_const<F: ~const Fn(&T, &T) -> Ordering + ~const Destruct>(
+        &self,
+        cmp: F,
+    ) -> Option<&T> {
+        if N == 0 {
+            return None;
+        }
+        let (mut min, mut i) = (0, 1);
+        while i < N {
+            if cmp(&self[i], &self[min]) == Ordering::Less {
+                min = i;
+            }
+            i += 1;
+        }
+        Some(&self[min])
+    }
+
+    fn run_boundaries<const CAP: usize, F: ~const Fn(&T, &T) -> bool + ~const Destruct>(
+        &self,
+        same: F,
+    ) -> ([usize; CAP], usize) {
+        let mut starts = [0; CAP];
+        let mut count = 0;
+        let mut i = 0;
+        while i < N {
+            if i == 0 || !same(&self[i - 1], &self[i]) {
+                if count < CAP {
+                    starts[count] = i;
+                }
+                count += 1; // keep counting past the capacity
+            }
+            i += 1;
+        }
+        (starts, count)
+    }
+
+    fn slice_to_array<const M: usize>(&self, start: usize) -> Option<[T; M]>
+    where
+        T: Copy,
+    {
+        if M > N || start > N - M {
+            return None; // start + M might overflow
+        }
+        unsafe { Some(read(self.as_ptr().add(start).cast())) } // copy from offset'ed pointer
+    }
+
+    fn rposition<F: ~const Fn(&T) -> bool + ~const Destruct>(&self, pred: F) -> Option<usize> {
+        let mut i = N;
+        while i > 0 {
+            i -= 1;
+            if pred(&self[i]) {
+                return Some(i);
+            }
+        }
+        None
+    }
+
+    fn is_sorted(&self) -> bool
+    where
+        T: ~const PartialOrd,
+    {
+        let mut i = 1;
+        while i < N {
+            if let None | Some(Ordering::Greater) = self[i - 1].partial_cmp(&self[i]) {
+                return false; // incomparable elements aren't sorted either
+            }
+            i += 1;
+        }
+        true
+    }
+
+    fn is_sorted_by<F: ~const Fn(&T, &T) -> bool + ~const Destruct>(&self, f: F) -> bool {
+        let mut i = 1;
+        while i < N {
+            if !f(&self[i - 1], &self[i]) {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    fn fold_rows<B: Copy, F: ~const FnMut(B, &T) -> B + ~const Destruct>(
+        &self,
+        init: B,
+        mut f: F,
+    ) -> [B; N] {
+        let mut result = [init; N];
+        let mut i = 0;
+        while i < N {
+            result[i] = f(init, &self[i]);
+            i += 1;
+        }
+        result
+    }
+
+    fn mask<F: ~const Fn(&T) -> bool + ~const Destruct>(&self, pred: F) -> [bool; N] {
+        let mut result = [false; N];
+        let mut i = 0;
+        while i < N {
+            result[i] = pred(&self[i]);
+            i += 1;
+        }
+        result
+    }
+}
+
+impl<const N: usize> const ArrayBytes<N> for [u8; N] {
+    fn fnv1a(&self) -> u64 {
+        let mut hash = 0xcbf29ce484222325; // offset basis
+        let mut i = 0;
+        while i < N {
+            hash ^= self[i] as u64;
+            hash = hash.wrapping_mul(0x100000001b3); // FNV prime
+            i += 1;
+        }
+        hash
+    }
+
+    fn to_u32_array_le(self) -> [u32; N / 4] {
+        let () = Assert::<N, 4>::DIVIDES; // reject incomplete words
+        let mut result = [0; N / 4];
+        let mut i = 0;
+        while i < N / 4 {
+            let b = [
+                self[4 * i],
+                self[4 * i + 1],
+                self[4 * i + 2],
+                self[4 * i + 3],
+            ];
+            result[i] = u32::from_le_bytes(b);
+            i += 1;
+        }
+        result
+    }
+
+    fn from_u32_array_le(words: [u32; N / 4]) -> [u8; N] {
+        let () = Assert::<N, 4>::DIVIDES; // reject incomplete words
+        let mut result = [0; N];
+        let mut i = 0;
+        while i < N / 4 {
+            let b = words[i].to_le_bytes();
+            (
+                result[4 * i],
+                result[4 * i + 1],
+                result[4 * i + 2],
+                result[4 * i + 3],
+            ) = (b[0], b[1], b[2], b[3]);
+            i += 1;
+        }
+        result
+    }
+
+    fn to_u32_array_be(self) -> [u32; N / 4] {
+        let () = Assert::<N, 4>::DIVIDES; // reject incomplete words
+        let mut result = [0; N / 4];
+        let mut i = 0;
+        while i < N / 4 {
+            let b = [
+                self[4 * i],
+                self[4 * i + 1],
+                self[4 * i + 2],
+                self[4 * i + 3],
+            ];
+            result[i] = u32::from_be_bytes(b);
+            i += 1;
+        }
+        result
+    }
+
+    fn from_u32_array_be(words: [u32; N / 4]) -> [u8; N] {
+        let () = Assert::<N, 4>::DIVIDES; // reject incomplete words
+        let mut result = [0; N];
+        let mut i = 0;
+        while i < N / 4 {
+            let b = words[i].to_be_bytes();
+            (
+                result[4 * i],
+                result[4 * i + 1],
+                result[4 * i + 2],
+                result[4 * i + 3],
+            ) = (b[0], b[1], b[2], b[3]);
+            i += 1;
+        }
+        result
+    }
+
+    fn parity(&self) -> bool {
+        let mut acc = 0;
+        let mut i = 0;
+        while i < N {
+            acc ^= self[i]; // xor keeps the parity of every bit position
+            i += 1;
+        }
+        acc.count_ones() % 2 == 1
+    }
+
+    fn wrapping_sum(&self) -> u8 {
+        let mut sum = 0u8;
+        let mut i = 0;
+        while i < N {
+            sum = sum.wrapping_add(self[i]);
+            i += 1;
+        }
+        sum
+    }
+
+    fn count_ones(&self) -> u32 {
+        let mut count = 0;
+        let mut i = 0;
+        while i < N {
+            count += self[i].count_ones();
+            i += 1;
+        }
+        count
+    }
+
+    fn is_zero(&self) -> bool {
+        let mut i = 0;
+        while i < N {
+            if self[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        flatten_options, interleave_channels, ArrayAdd, ArrayAddIter, ArrayBytes, ArrayInit,
+        ArrayQuery, ArrayRemove, ArraySplit, ArrayTransform, ConstVec, ToTuple,
+    };
+
+    #[test]
+    fn push_back_noncopy() {
+        let input = [vec![1, 2], vec![3, 4]];
+        let expected = [vec![1, 2], vec![3, 4], vec![5, 6, 7]];
+        let result = input.push_back(vec![5, 6, 7]);
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn push_front_noncopy() {
+        let input = [vec![1, 2], vec![3, 4]];
+        let expected = [vec![254, 255, 0], vec![1, 2], vec![3, 4]];
+        let result = input.push_front(vec![254, 255, 0]);
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn concat_noncopy() {
+        let input = [vec![1, 2], vec![3, 4]];
+        let expected = [
+            vec![1, 2],
+            vec![3, 4],
+            vec![5, 6, 7],
+            vec![8, 9],
+            vec![10, 11, 12],
+        ];
+        let result = input.concat([vec![5, 6, 7], vec![8, 9], vec![10, 11, 12]]);
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn concat_back_noncopy() {
+        let input = [vec![1, 2], vec![3, 4]];
+        let expected = [
+            vec![249, 250, 251],
+            vec![252, 253],
+            vec![254, 255, 0],
+            vec![1, 2],
+            vec![3, 4],
+        ];
+        let result = input.concat_back([vec![249, 250, 251], vec![252, 253], vec![254, 255, 0]]);
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn concat_back_rev_noncopy() {
+        let input = [vec![1, 2], vec![3, 4]];
+        let expected = [vec![254, 255, 0], vec![252, 253], vec![1, 2], vec![3, 4]];
+        let result = input.concat_back_rev([vec![252, 253], vec![254, 255, 0]]);
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn concat_into_noncopy() {
+        let input = [vec![1, 2], vec![3, 4]];
+        let expected = [vec![1, 2], vec![3, 4], vec![5, 6, 7]];
+        let mut buffer = Box::new_uninit();
+        let result = input.concat_into([vec![5, 6, 7]], &mut buffer);
+        assert_eq!(&expected, result);
+        let result = unsafe { buffer.assume_init() };
+        assert_eq!(expected, *result)
+    }
+
+    #[test]
+    fn truncate_start_noncopy() {
+        let input = [vec![1, 2], vec![3, 4], vec![5, 6], vec![7, 8]];
+        let expected = [vec![5, 6], vec![7, 8]];
+        let result = input.truncate_start::<2>();
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn truncate_end_noncopy() {
+        let input = [vec![1, 2], vec![3, 4], vec![5, 6], vec![7, 8]];
+        let expected = [vec![1, 2], vec![3, 4]];
+        let result = input.truncate_end::<2>();
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn truncate_end_drop_noncopy() {
+        // L != N / 2, so dropping self[L..] instead of the back would show
+        let counters = [(); 5].map(|_| std::rc::Rc::new(()));
+        let counts = || counters.each_ref().map(std::rc::Rc::strong_count);
+        let result = counters.clone().truncate_end::<1>();
+        assert_eq!([2, 2, 2, 2, 1], counts());
+        drop(result);
+        let result = counters.clone().truncate_end::<3>();
+        assert_eq!([2, 2, 1, 1, 1], counts());
+        drop(result);
+        assert_eq!([1; 5], counts())
+    }
+
+    #[test]
+    fn split_first_noncopy() {
+        let input = [vec![1, 2], vec![3, 4], vec![5, 6], vec![7, 8]];
+        let expected = (vec![1, 2], [vec![3, 4], vec![5, 6], vec![7, 8]]);
+        let result = input.split_first();
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn split_last_noncopy() {
+        let input = [vec![1, 2], vec![3, 4], vec![5, 6], vec![7, 8]];
+        let expected = (vec![7, 8], [vec![1, 2], vec![3, 4], vec![5, 6]]);
+        let result = input.split_last();
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn swap_remove_noncopy() {
+        let input = [vec![1, 2], vec![3, 4], vec![5, 6], vec![7, 8]];
+        let expected = (vec![3, 4], [vec![1, 2], vec![7, 8], vec![5, 6]]);
+        let result = input.swap_remove::<1>();
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn swap_remove_last_noncopy() {
+        let input = [vec![1, 2], vec![3, 4], vec![5, 6], vec![7, 8]];
+        let expected = (vec![7, 8], [vec![1, 2], vec![3, 4], vec![5, 6]]);
+        let result = input.swap_remove::<3>();
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn into_iter_move_noncopy() {
+        let counter = std::rc::Rc::new(());
+        let input = [(); 4].map(|_| counter.clone());
+        let mut iter = input.into_iter_move();
+        let yielded = [iter.next(), iter.next_back()];
+        assert_eq!(2, iter.len());
+        drop(iter); // drops the 2 elements left
+        assert_eq!(3, std::rc::Rc::strong_count(&counter));
+        drop(yielded);
+        assert_eq!(1, std::rc::Rc::strong_count(&counter))
+    }
+
+    #[test]
+    fn interleave_noncopy() {
+        let left = [vec![1], vec![3]];
+        let right = [vec![2], vec![4]];
+        let expected = [vec![1], vec![2], vec![3], vec![4]];
+        let result = left.interleave(right);
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn deinterleave_noncopy() {
+        let input = [vec![1], vec![2], vec![3], vec![4]];
+        let expected = ([vec![1], vec![3]], [vec![2], vec![4]]);
+        let result = input.deinterleave();
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn update_at_noncopy() {
+        let input = [vec![1, 2], vec![3, 4], vec![5, 6], vec![7, 8]];
+        let expected = [vec![1, 2], vec![3, 4], vec![5, 6, 7], vec![7, 8]];
+        let result = input.update_at::<2, _>(|mut e| {
+            e.push(7);
+            e
+        });
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn update_at_panic_noncopy() {
+        let counter = std::rc::Rc::new(());
+        let input = [(); 4].map(|_| counter.clone());
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            input.update_at::<2, _>(|_| panic!("update failed"))
+        }));
+        assert!(result.is_err());
+        assert_eq!(1, std::rc::Rc::strong_count(&counter)) // every element got dropped once
+    }
+
+    #[test]
+    fn position_noncopy() {
+        let input = [vec![1, 2], vec![3, 4], vec![3, 4]];
+        assert_eq!(Some(1), input.position(&vec![3, 4]));
+        assert_eq!(None, input.position(&vec![5, 6]));
+        assert!(input.contains(&vec![1, 2]))
+    }
+
+    #[test]
+    fn split_ref_noncopy() {
+        let input = [vec![1, 2], vec![3, 4], vec![5, 6]];
+        let (left, right) = input.split_ref::<1>();
+        assert_eq!(&[vec![1, 2]], left);
+        assert_eq!(&[vec![3, 4], vec![5, 6]], right)
+    }
+
+    #[test]
+    fn split_mut_noncopy() {
+        let mut input = [vec![1, 2], vec![3, 4], vec![5, 6]];
+        let expected = [vec![1, 2, 7], vec![3, 4, 8], vec![5, 6]];
+        let (left, right) = input.split_mut::<1>();
+        left[0].push(7);
+        right[0].push(8);
+        assert_eq!(expected, input)
+    }
+
+    #[test]
+    fn to_tuple_noncopy() {
+        let input = [vec![1, 2], vec![3, 4]];
+        let expected = (vec![1, 2], vec![3, 4]);
+        let result = input.to_tuple();
+        assert_eq!(expected, result);
+        assert_eq!([vec![1, 2], vec![3, 4]], <[Vec<u8>; 2]>::from_tuple(result))
+    }
+
+    #[test]
+    fn get_and_replace_noncopy() {
+        let input = [vec![1, 2], vec![3, 4]];
+        let expected = Ok(([vec![1, 2], vec![5]], vec![3, 4]));
+        let result = input.get_and_replace(1, vec![5]);
+        assert_eq!(expected, result);
+        let (input, _) = result.unwrap();
+        let expected = Err(([vec![1, 2], vec![5]], vec![6]));
+        let result = input.get_and_replace(2, vec![6]);
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn scan_const_noncopy() {
+        let input = [vec![1, 2], vec![3], vec![4, 5, 6]];
+        let expected = [2, 3, 6];
+        let result = input.scan_const(0, |acc, e| acc + e.len());
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn keep_ends_noncopy() {
+        let counter = std::rc::Rc::new(());
+        let input = [vec![1], vec![2], vec![3], vec![4], vec![5]];
+        let middle = [(); 2].map(|_| counter.clone());
+        let expected = [vec![1], vec![2], vec![5]];
+        let result = input.keep_ends::<2, 1>();
+        assert_eq!(expected, result);
+        assert_eq!(0, middle.keep_ends::<0, 0>().len());
+        assert_eq!(1, std::rc::Rc::strong_count(&counter)) // the middle got dropped
+    }
+
+    #[test]
+    fn try_map_each_noncopy() {
+        let input = [vec![1], vec![2], vec![3]];
+        let expected: Result<_, ()> = Ok([1, 2, 3]);
+        let result = input.try_map_each(|e| Ok(e[0]));
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn try_map_each_err_noncopy() {
+        let counter = std::rc::Rc::new(());
+        let input = [(); 5].map(|_| counter.clone());
+        let mut calls = 0;
+        let result = input.try_map_each(|e| {
+            calls += 1;
+            if calls == 3 {
+                Err(calls)
+            } else {
+                Ok(e)
+            }
+        });
+        assert_eq!(Err(3), result.map(|_| ()));
+        assert_eq!(1, std::rc::Rc::strong_count(&counter)) // nothing leaked
+    }
+
+    #[test]
+    fn try_map_each_panic_noncopy() {
+        let counter = std::rc::Rc::new(());
+        let input = [(); 5].map(|_| counter.clone());
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let mut calls = 0;
+            input.try_map_each(|e| -> Result<_, ()> {
+                calls += 1;
+                assert_ne!(calls, 3, "conversion failed");
+                Ok(e)
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(1, std::rc::Rc::strong_count(&counter)) // nothing leaked
+    }
+
+    #[test]
+    fn group_pairs_noncopy() {
+        let input = [vec![1], vec![2], vec![3], vec![4]];
+        let expected = [[vec![1], vec![2]], [vec![3], vec![4]]];
+        let result = input.group_pairs();
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn enumerate_const_noncopy() {
+        let counter = std::rc::Rc::new(());
+        let input = [vec![1], vec![2], vec![3]];
+        let expected = [(0, vec![1]), (1, vec![2]), (2, vec![3])];
+        let result = input.enumerate_const();
+        assert_eq!(expected, result);
+        let tagged = [(); 3].map(|_| counter.clone()).enumerate_const();
+        assert_eq!(4, std::rc::Rc::strong_count(&counter));
+        drop(tagged);
+        assert_eq!(1, std::rc::Rc::strong_count(&counter)) // every element got dropped once
+    }
+
+    #[test]
+    fn argsort_noncopy() {
+        let input = [vec![3], vec![1], vec![2], vec![1]];
+        let expected = [1, 3, 2, 0];
+        let result = input.argsort();
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn min_max_const_noncopy() {
+        let input = [vec![2], vec![1, 0], vec![3], vec![1]];
+        assert_eq!(Some(&vec![1]), input.min_const());
+        assert_eq!(Some(&vec![3]), input.max_const());
+        assert!(std::ptr::eq(
+            &input[0],
+            input.min_by_key_const(|e| e.len()).unwrap()
+        )); // the first shortest
+        assert_eq!(
+            None,
+            <[Vec<u8>; 0]>::default().min_by_key_const(|e| e.len())
+        );
+    }
+
+    #[test]
+    fn fold_const_noncopy() {
+        let input = [vec![1], vec![2, 3], vec![4]];
+        let result = input.fold_const(Vec::new(), |mut acc, e| {
+            acc.extend(e);
+            acc
+        });
+        assert_eq!(vec![1, 2, 3, 4], result);
+        let input = [vec![1], vec![2, 3], vec![4]];
+        let result = input.reduce_const(|mut acc, e| {
+            acc.extend(e);
+            acc
+        });
+        assert_eq!(Some(vec![1, 2, 3, 4]), result);
+    }
+
+    #[test]
+    fn fold_const_panic_noncopy() {
+        let counter = std::rc::Rc::new(());
+        let input = [(); 4].map(|_| counter.clone());
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            input.fold_const(0, |acc, e| {
+                drop(e);
+                assert!(acc < 1, "second element");
+                acc + 1
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(1, std::rc::Rc::strong_count(&counter)) // nothing leaked
+    }
+
+    #[test]
+    fn concat_map_noncopy() {
+        let input = [String::from("a"), String::from("b")];
+        let expected = ["a", "b", "c", "d"].map(String::from);
+        let result = input.concat_map(["c", "d"]);
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn concat_map_panic_noncopy() {
+        struct Bomb(std::rc::Rc<()>, bool);
+        impl From<Bomb> for std::rc::Rc<()> {
+            fn from(bomb: Bomb) -> Self {
+                assert!(!bomb.1, "conversion failed");
+                bomb.0.clone()
+            }
+        }
+        let counter = std::rc::Rc::new(());
+        let input = [(); 2].map(|_| counter.clone());
+        let array = [false, true, false].map(|fails| Bomb(counter.clone(), fails));
+        let result =
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| input.concat_map(array)));
+        assert!(result.is_err());
+        assert_eq!(1, std::rc::Rc::strong_count(&counter)) // nothing leaked or dropped twice
+    }
+
+    #[test]
+    fn pipe_noncopy() {
+        let input = [vec![1], vec![2]];
+        let expected = [vec![0], vec![1], vec![2], vec![3]];
+        let result = input
+            .pipe(|a| a.push_front(vec![0]))
+            .pipe(|a| a.push_back(vec![3]));
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn rchunks_noncopy() {
+        let input = [vec![1], vec![2], vec![3], vec![4]];
+        let expected = [[vec![3], vec![4]], [vec![1], vec![2]]];
+        let result = input.rchunks::<2>();
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn chunks_with_remainder_noncopy() {
+        let counter = std::rc::Rc::new(());
+        let input = [vec![1], vec![2], vec![3], vec![4], vec![5]];
+        let (chunks, remainder) = input.chunks_with_remainder::<2>();
+        assert_eq!([[vec![1], vec![2]], [vec![3], vec![4]]], chunks);
+        assert_eq!([vec![5]], remainder);
+        let parts = [(); 5]
+            .map(|_| counter.clone())
+            .chunks_with_remainder::<3>();
+        drop(parts);
+        assert_eq!(1, std::rc::Rc::strong_count(&counter)) // every element got dropped once
+    }
+
+    #[test]
+    fn const_vec_noncopy() {
+        let counter = std::rc::Rc::new(());
+        let mut vec = ConstVec::<_, 4>::new();
+        assert_eq!(Ok(()), vec.push(counter.clone()));
+        assert!(vec
+            .extend_from_array([(); 3].map(|_| counter.clone()))
+            .is_ok());
+        assert!(vec.push(counter.clone()).is_err());
+        assert!(vec.extend_from_array([counter.clone()]).is_err());
+        assert_eq!(5, std::rc::Rc::strong_count(&counter));
+        let vec = vec.into_array::<3>().unwrap_err();
+        drop(vec);
+        assert_eq!(1, std::rc::Rc::strong_count(&counter)); // every element got dropped once
+        let mut vec = ConstVec::<_, 2>::new();
+        let _ = vec.push(vec![1]).and(vec.push(vec![2]));
+        assert_eq!(
+            Ok([vec![1], vec![2]]),
+            vec.into_array::<2>().map_err(|_| ())
+        );
+    }
+
+    #[test]
+    fn binary_search_noncopy() {
+        let input = [String::from("a"), String::from("c"), String::from("e")];
+        assert_eq!(Ok(1), input.binary_search(&String::from("c")));
+        assert_eq!(Err(3), input.binary_search(&String::from("f")));
+    }
+
+    #[test]
+    fn concat_arrays_noncopy() {
+        let expected = [vec![1], vec![2], vec![3]];
+        let result = concat_arrays!([vec![1]], [vec![2], vec![3]],);
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn concat_maybe_uninit_noncopy() {
+        use core::mem::MaybeUninit;
+
+        let counter = std::rc::Rc::new(());
+        let input = [MaybeUninit::new(vec![1]), MaybeUninit::uninit()];
+        let array = [MaybeUninit::uninit(), MaybeUninit::new(vec![4])];
+        let mut result = input.concat(array);
+        let mut back = [MaybeUninit::new(counter.clone())].concat_back([MaybeUninit::uninit()]);
+        unsafe {
+            assert_eq!(vec![1], result[0].assume_init_read());
+            assert_eq!(vec![4], result[3].assume_init_read());
+            result[1].write(vec![2]); // the uninit slots are still usable
+            assert_eq!(vec![2], result[1].assume_init_read());
+            assert_eq!(2, std::rc::Rc::strong_count(&counter)); // nothing got dropped
+            back[1].assume_init_drop();
+        }
+        assert_eq!(1, std::rc::Rc::strong_count(&counter))
+    }
+
+    #[test]
+    fn build_noncopy() {
+        let expected = [vec![], vec![1], vec![2, 2]];
+        let result = <[Vec<usize>; 3]>::build(|i| vec![i; i]);
+        assert_eq!(expected, result);
+        let counter = std::rc::Rc::new(());
+        let result = <[_; 4]>::try_build(|i| if i < 3 { Ok(counter.clone()) } else { Err(i) });
+        assert_eq!(Err(3), result);
+        assert_eq!(1, std::rc::Rc::strong_count(&counter)) // the created elements got dropped
+    }
+
+    #[test]
+    fn build_panic_noncopy() {
+        let counter = std::rc::Rc::new(());
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            <[_; 4]>::build(|i| {
+                assert!(i < 2, "third element");
+                counter.clone()
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(1, std::rc::Rc::strong_count(&counter)) // nothing leaked
+    }
+
+    #[test]
+    fn count_noncopy() {
+        let input = [vec![1], vec![], vec![2, 3], vec![]];
+        assert_eq!(2, input.count(|e| e.is_empty()));
+        assert_eq!(0, input.count(|e| e.len() > 2));
+    }
+
+    #[test]
+    fn all_any_noncopy() {
+        let input = [vec![1], vec![2, 3]];
+        assert_eq!(
+            (true, false),
+            (input.all(|e| !e.is_empty()), input.any(|e| e.is_empty()))
+        );
+        let calls = core::cell::Cell::new(0);
+        let any = input.any(|_| {
+            calls.set(calls.get() + 1);
+            true
+        });
+        assert_eq!((true, 1), (any, calls.get())) // stopped at the first element
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn concat_boxed_noncopy() {
+        use crate::ArrayBoxed;
+
+        let counter = std::rc::Rc::new(());
+        let input = Box::new([vec![1], vec![2]]);
+        let expected = Box::new([vec![1], vec![2], vec![3]]);
+        let result = input.concat_boxed(Box::new([vec![3]]));
+        assert_eq!(expected, result);
+        let result = Box::new([counter.clone()]).concat_boxed(Box::new([counter.clone()]));
+        assert_eq!(3, std::rc::Rc::strong_count(&counter));
+        drop(result);
+        assert_eq!(1, std::rc::Rc::strong_count(&counter)) // every element got dropped once
+    }
+
+    #[test]
+    fn map_each_noncopy() {
+        let input = [vec![1], vec![2, 3]];
+        let expected = [vec![1, 0], vec![2, 3, 0]];
+        let result = input.map_each(|mut e| {
+            e.push(0);
+            e
+        });
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn positions_noncopy() {
+        let input = [vec![], vec![1], vec![]];
+        assert_eq!(([0, 2], 2), input.positions::<2, _>(|e| e.is_empty()));
+    }
+
+    #[test]
+    fn eq_by_noncopy() {
+        let input = [String::from("a"), String::from("bc")];
+        assert!(input.eq_by(&[1, 2], |a, b| a.len() == *b));
+        assert!(!input.eq_by(&["a", "BC"], |a, b| a == b));
+    }
+
+    #[test]
+    fn split_off_start_noncopy() {
+        let counter = std::rc::Rc::new(());
+        let input = [vec![1], vec![2], vec![3]];
+        let expected = ([vec![1], vec![2]], [vec![3]]);
+        let result = input.split_off_start::<2>();
+        assert_eq!(expected, result);
+        let parts = [(); 3].map(|_| counter.clone()).split_off_start::<1>();
+        assert_eq!(4, std::rc::Rc::strong_count(&counter)); // nothing got dropped
+        drop(parts);
+        assert_eq!(1, std::rc::Rc::strong_count(&counter))
+    }
+
+    #[test]
+    fn rotate_left_inplace_noncopy() {
+        let mut input = [vec![1], vec![2], vec![3], vec![4]];
+        input.rotate_left_inplace::<3>();
+        assert_eq!([vec![4], vec![1], vec![2], vec![3]], input);
+        let mut expected = input.clone();
+        expected.rotate_left(2);
+        input.rotate_left_inplace::<2>();
+        assert_eq!(expected, input)
+    }
+
+    #[test]
+    fn extend_by_noncopy() {
+        fn grow<const N: usize>(array: [Vec<u8>; N]) -> [Vec<u8>; N + 2]
+        where
+            [(); N + 2]:,
+        {
+            array.extend_by([vec![], vec![0]])
+        }
+        let expected = [vec![1], vec![], vec![0]];
+        let result = grow([vec![1]]);
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn interleave_channels_noncopy() {
+        let channels = [[vec![1], vec![4]], [vec![2], vec![5]], [vec![3], vec![6]]];
+        let expected = [vec![1], vec![2], vec![3], vec![4], vec![5], vec![6]];
+        let result = interleave_channels(channels);
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn map_chunks_noncopy() {
+        let input = [vec![1], vec![2], vec![3], vec![4]];
+        let expected = [vec![1, 2], vec![], vec![3, 4], vec![]];
+        let result = input.map_chunks(|[mut a, b]: [Vec<u8>; 2]| {
+            a.extend(b);
+            [a, vec![]]
+        });
+        assert_eq!(expected, result);
+        let counter = std::rc::Rc::new(());
+        let input = [(); 4].map(|_| counter.clone());
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let mut calls = 0;
+            input.map_chunks(|chunk: [_; 2]| {
+                calls += 1;
+                if calls == 2 {
+                    panic!("expected");
+                }
+                chunk
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(1, std::rc::Rc::strong_count(&counter)) // nothing leaked
+    }
+
+    #[test]
+    fn append_from_iter_noncopy() {
+        let input = [vec![1]];
+        let mut iter = vec![vec![2], vec![3], vec![4]].into_iter();
+        let expected = [vec![1], vec![2], vec![3]];
+        let result = input.append_from_iter::<_, 2>(iter.by_ref());
+        assert_eq!(Some(expected), result);
+        assert_eq!(vec![vec![4]], iter.collect::<Vec<_>>()); // extras are left
+        let counter = std::rc::Rc::new(());
+        let input = [counter.clone()];
+        let result = input.append_from_iter::<_, 3>(vec![counter.clone()]);
+        assert_eq!(None, result);
+        assert_eq!(1, std::rc::Rc::strong_count(&counter)) // nothing leaked
+    }
+
+    #[test]
+    fn into_halves_noncopy() {
+        let input = [vec![1], vec![2], vec![3]];
+        let expected = ([vec![1]], [vec![2], vec![3]]);
+        let result = input.into_halves();
+        assert_eq!(expected, result);
+        let input = [vec![1], vec![2], vec![3], vec![4], vec![5]];
+        let expected = ([vec![1]], [vec![2]], [vec![3]], [vec![4], vec![5]]);
+        let result = input.into_quarters();
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn specialization_noncopy() {
+        // Drop but not Copy: the base impls must drop exactly what's removed
+        let counter = std::rc::Rc::new(());
+        let new = || [(); 4].map(|_| counter.clone());
+        let count = || std::rc::Rc::strong_count(&counter) - 1;
+        let result = new().concat(new());
+        assert_eq!(8, count());
+        drop(result);
+        let result = new().interleave(new());
+        assert_eq!(8, count());
+        drop(result);
+        let result = new().truncate_start::<1>();
+        assert_eq!(3, count());
+        drop(result);
+        let result = new().truncate_end::<3>();
+        assert_eq!(1, count());
+        drop(result);
+        let result = new().swap_remove::<1>();
+        assert_eq!(4, count());
+        drop(result);
+        let result = new().keep_ends::<1, 1>();
+        assert_eq!(2, count());
+        drop(result);
+        let result = new().into_quarters();
+        assert_eq!(4, count());
+        drop(result);
+        assert_eq!(0, count());
+        // neither Copy nor Drop: the base impls must still move every element
+        #[derive(Debug, PartialEq)]
+        struct Plain(u8);
+        let new = || [Plain(1), Plain(2), Plain(3)];
+        assert_eq!(
+            [Plain(1), Plain(2), Plain(3), Plain(1)],
+            new().concat([Plain(1)])
+        );
+        assert_eq!([Plain(2), Plain(3)], new().truncate_start::<1>());
+        assert_eq!((Plain(1), [Plain(3), Plain(2)]), new().swap_remove::<0>());
+        // Copy, so never Drop: the specialized impls copy without dropping
+        let new = || [&counter; 3];
+        assert_eq!(6, new().concat(new()).len());
+        assert_eq!(2, new().truncate_end::<1>().len());
+        assert_eq!(0, count())
+    }
+
+    #[test]
+    fn zst_noncopy() {
+        // zero-sized elements still have to be dropped exactly once
+        std::thread_local!(static DROPS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) });
+        #[derive(Debug)]
+        struct Token;
+        impl Drop for Token {
+            fn drop(&mut self) {
+                DROPS.set(DROPS.get() + 1)
+            }
+        }
+        let new = || [(); 4].map(|_| Token);
+        let result = new().concat(new()).push_back(Token);
+        assert_eq!((9, 0), (result.len(), DROPS.get()));
+        drop(result);
+        assert_eq!(9, DROPS.take());
+        let result = new().truncate_start::<1>().truncate_end::<2>();
+        assert_eq!((1, 3), (result.len(), DROPS.get()));
+        drop(result);
+        assert_eq!(4, DROPS.take());
+        let (first, rest) = new().interleave(new()).split_first();
+        assert_eq!((7, 0), (rest.len(), DROPS.get()));
+        drop((first, rest));
+        assert_eq!(8, DROPS.take())
+    }
+
+    #[test]
+    fn append_many_noncopy() {
+        let input = [vec![1]];
+        let expected = [vec![1], vec![2], vec![3]];
+        let result = input.append_many([vec![2], vec![3]]);
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn concat_reversed_noncopy() {
+        let input = [vec![1, 2], vec![3, 4]];
+        let expected = [vec![1, 2], vec![3, 4], vec![254, 255, 0], vec![252, 253]];
+        let result = input.concat_reversed([vec![252, 253], vec![254, 255, 0]]);
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn flatten_options_noncopy() {
+        let counter = std::rc::Rc::new(());
+        let input = [None, Some(counter.clone()), None, Some(counter.clone())];
+        let result = flatten_options(input);
+        assert_eq!((2, 3), (result.len(), std::rc::Rc::strong_count(&counter)));
+        drop(result);
+        assert_eq!(1, std::rc::Rc::strong_count(&counter));
+        let input = [Some(vec![1]), None, Some(vec![3])];
+        let result = flatten_options(input);
+        assert_eq!(&[vec![1], vec![3]], result.as_slice())
+    }
+
+    #[test]
+    fn reverse_inplace_noncopy() {
+        let counter = std::rc::Rc::new(());
+        let mut input = [vec![1], vec![2], vec![3], vec![4]];
+        input.reverse_inplace();
+        assert_eq!([vec![4], vec![3], vec![2], vec![1]], input);
+        let mut input = [(); 4].map(|_| counter.clone());
+        input.reverse_inplace();
+        assert_eq!(5, std::rc::Rc::strong_count(&counter)); // nothing got dropped
+        drop(input);
+        assert_eq!(1, std::rc::Rc::strong_count(&counter))
+    }
+
+    #[test]
+    fn select_noncopy() {
+        let input = [vec![1], vec![2], vec![3]];
+        let expected = [vec![1], vec![20], vec![30]];
+        let result = input.select([vec![10], vec![20], vec![30]], [true, false, false]);
+        assert_eq!(expected, result);
+        let (kept, discarded) = (std::rc::Rc::new(()), std::rc::Rc::new(()));
+        let result = [(); 4]
+            .map(|_| kept.clone())
+            .select([(); 4].map(|_| discarded.clone()), [true; 4]);
+        assert_eq!(
+            (5, 1),
+            (
+                std::rc::Rc::strong_count(&kept),
+                std::rc::Rc::strong_count(&discarded)
+            )
+        );
+        drop(result);
+        assert_eq!(1, std::rc::Rc::strong_count(&kept))
+    }
+
+    #[test]
+    fn drop_stress_noncopy() {
+        use std::{cell::Cell, cell::RefCell, collections::HashSet, panic};
+        // every living Tracked is registered, so dropping one twice panics
+        // and never dropping one is left registered
+        std::thread_local! {
+            static LIVE: RefCell<HashSet<u64>> = RefCell::new(HashSet::new());
+            static NEXT: Cell<u64> = const { Cell::new(0) };
+        }
+        #[derive(Debug)]
+        struct Tracked(u64);
+        impl Tracked {
+            fn new() -> Self {
+                let id = NEXT.replace(NEXT.get() + 1);
+                LIVE.with_borrow_mut(|live| live.insert(id));
+                Self(id)
+            }
+        }
+        impl Drop for Tracked {
+            fn drop(&mut self) {
+                let alive = LIVE.with_borrow_mut(|live| live.remove(&self.0));
+                assert!(alive, "{} was dropped twice", self.0)
+            }
+        }
+        let mut seed = 0x9e3779b97f4a7c15u64;
+        let mut random = move |below: usize| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed as usize % below
+        }; // xorshift, so every run is the same
+        macro_rules! stress {
+            ($($n:literal),*) => {$({
+                const N: usize = $n;
+                let new = || core::array::from_fn::<_, N, _>(|_| Tracked::new());
+                for _ in 0..32 {
+                    drop(new().push_back(Tracked::new()).push_front(Tracked::new()));
+                    drop(new().concat(new()).concat_back(new()).append_many(new()));
+                    drop(new().concat_back_rev(new()).concat_reversed(new()));
+                    drop(new().interleave(new()));
+                    drop(new().truncate_start::<1>().truncate_end::<1>());
+                    drop((new().split_first(), new().split_last(), new().swap_remove::<1>()));
+                    drop((new().keep_ends::<1, 1>(), new().split_off_start::<2>()));
+                    drop((new().into_halves(), new().into_quarters()));
+                    let mut iter = new().into_iter_move();
+                    (0..random(N + 1)).for_each(|_| drop(iter.next()));
+                    drop(iter);
+                    let mask = core::array::from_fn::<_, N, _>(|_| random(2) == 0);
+                    drop(new().select(new(), mask));
+                    drop(flatten_options(mask.map(|m| m.then(Tracked::new))));
+                    let available = random(4);
+                    drop(new().append_from_iter::<_, 2>((0..available).map(|_| Tracked::new())));
+                    let mut array = new();
+                    array.reverse_inplace();
+                    array.rotate_left_inplace::<1>();
+                    drop((array, interleave_channels([new(), new(), new()])));
+                    drop(new().map_chunks(|chunk: [Tracked; 1]| chunk));
+                    let mut vec = ConstVec::<Tracked, N>::new();
+                    (0..random(N + 2)).for_each(|_| drop(vec.push(Tracked::new())));
+                    drop(vec.into_array::<N>());
+                    let fail = random(N + 1);
+                    let mut i = 0;
+                    drop(new().try_map_each(|e| {
+                        i += 1;
+                        if i == fail { Err(e) } else { Ok(e) }
+                    }));
+                    let built = panic::catch_unwind(|| {
+                        <[Tracked; N]>::build(|i| if i == fail { panic!("expected") } else { Tracked::new() })
+                    });
+                    drop(built);
+                    assert_eq!(0, LIVE.with_borrow(HashSet::len), "elements were leaked");
+                }
+            })*};
+        }
+        stress!(3, 4, 5, 8);
+    }
+
+    #[test]
+    fn push_back_copy() {
+        let input = [1, 2, 3, 4];
+        let expected = [1, 2, 3, 4, 5];
+        let result = input.push_back(5);
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn push_front_copy() {
+        let input = [1, 2, 3, 4];
+        let expected = [0, 1, 2, 3, 4];
+        let result = input.push_front(0);
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn append_deprecated_copy() {
+        let input = [1, 2, 3, 4];
+        assert_eq!(input.push_back(5), input.append(5));
+        assert_eq!(input.push_front(0), input.append_back(0))
+    }
+
+    #[test]
+    fn append_array_copy() {
+        let input = [1, 2, 3, 4];
+        assert_eq!(input.concat([5, 6, 7]), input.append_array([5, 6, 7]));
+        assert_eq!(
+            input.concat_back([254, 255, 0]),
+            input.prepend_array([254, 255, 0])
+        )
+    }
+
+    #[test]
+    fn concat_copy() {
+        let input = [1, 2, 3, 4];
+        let expected = [1, 2, 3, 4, 5, 6, 7];
+        let result = input.concat([5, 6, 7]);
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn concat_ref_copy() {
+        let input = [1, 2, 3, 4];
+        let other = [5, 6, 7];
+        let expected = [1, 2, 3, 4, 5, 6, 7];
+        let result = input.concat_ref(&other);
+        assert_eq!(expected, result);
+        assert_eq!(input.concat(other), result)
+    }
+
+    #[test]
+    fn concat_const_copy() {
+        const SUFFIX: [u8; 3] = [0xde, 0xad, 0x00];
+        let input = [1, 2, 3, 4];
+        let expected = [1, 2, 3, 4, 0xde, 0xad, 0x00];
+        let result = input.concat_const(&SUFFIX);
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn concat_back_copy() {
+        let input = [1, 2, 3, 4];
+        let expected = [254, 255, 0, 1, 2, 3, 4];
+        let result = input.concat_back([254, 255, 0]);
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn concat_back_order_copy() {
+        let input = [0x10, 0x20];
+        let array = [0xa1, 0xb2, 0xc3]; // not palindromic, so a reversal shows up
+        let result = input.concat_back(array);
+        assert_eq!(5, result.len());
+        assert_eq!(array[0], result[0]);
+        assert_eq!(array[1], result[1]);
+        assert_eq!(array[2], result[2]);
+        assert_eq!(input[0], result[3]);
+        assert_eq!(input[1], result[4]);
+        let mut i = 0;
+        while i < 5 {
+            assert_eq!(input.concat(array)[(i + 2) % 5], result[i]); // same as rotating concat
+            i += 1;
+        }
+    }
+
+    #[test]
+    fn concat_back_rev_copy() {
+        let input = [1, 2, 3, 4];
+        let expected = [0, 255, 254, 1, 2, 3, 4];
+        let result = input.concat_back_rev([254, 255, 0]);
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn concat_into_copy() {
+        let input = [1u8; 4096];
+        let mut buffer = Box::new_uninit();
+        let result = input.concat_into([2u8; 4096], &mut buffer);
+        assert_eq!([1, 2], [result[4095], result[4096]]);
+        let result = unsafe { buffer.assume_init() };
+        assert!(result[..4096].iter().all(|e| *e == 1));
+        assert!(result[4096..].iter().all(|e| *e == 2))
+    }
+
+    #[test]
+    fn truncate_start_copy() {
+        let input = [1, 2, 3, 4];
+        let expected = [3, 4];
+        let result = input.truncate_start::<2>();
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn truncate_end_copy() {
+        let input = [1, 2, 3, 4];
+        let expected = [1, 2];
+        let result = input.truncate_end::<2>();
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn split_first_copy() {
+        let input = [1, 2, 3, 4];
+        let expected = (1, [2, 3, 4]);
+        let result = input.split_first();
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn split_last_copy() {
+        let input = [1, 2, 3, 4];
+        let expected = (4, [1, 2, 3]);
+        let result = input.split_last();
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn windows_copy() {
+        let input = [1, 2, 3, 4];
+        let expected = [[1, 2], [2, 3], [3, 4]];
+        let result = input.windows::<2>();
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn windows_full_copy() {
+        let input = [1, 2, 3, 4];
+        let expected = [[1, 2, 3, 4]];
+        let result = input.windows::<4>();
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn swap_remove_copy() {
+        let input = [1, 2, 3, 4];
+        let expected = (1, [4, 2, 3]);
+        let result = input.swap_remove::<0>();
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn into_iter_move_copy() {
+        let input = [1, 2, 3, 4];
+        let mut iter = input.into_iter_move();
+        assert_eq!(Some(4), iter.next_back());
+        assert_eq!(vec![1, 2, 3], iter.collect::<Vec<_>>());
+        assert_eq!(None, [0u8; 0].into_iter_move().next())
+    }
+
+    #[test]
+    fn interleave_copy() {
+        let left = [1, 3, 5];
+        let right = [2, 4, 6];
+        let expected = [1, 2, 3, 4, 5, 6];
+        let result = left.interleave(right);
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn deinterleave_copy() {
+        let input = [1, 2, 3, 4, 5, 6];
+        let expected = ([1, 3, 5], [2, 4, 6]);
+        let result = input.deinterleave();
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn fill_copy() {
+        let expected = [7, 7, 7, 7];
+        let result = <[u8; 4]>::fill(7);
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn from_slice_copy() {
+        let input: &[u8] = &[1, 2, 3, 4];
+        assert_eq!(Some([1, 2, 3, 4]), <[u8; 4]>::from_slice(input));
+        assert_eq!(None, <[u8; 5]>::from_slice(input));
+        assert_eq!(None, <[u8; 3]>::from_slice(input));
+        assert_eq!(Some([]), <[u8; 0]>::from_slice(&[]))
+    }
+
+    #[test]
+    fn from_slice_or_copy() {
+        let input: &[usize] = &[1, 2, 3];
+        assert_eq!(Some([1, 2, 3]), <[usize; 3]>::from_slice_or(input, |_| 0));
+        assert_eq!(
+            Some([1, 2, 3, 30, 40]),
+            <[usize; 5]>::from_slice_or(input, |i| i * 10)
+        );
+        assert_eq!(None, <[usize; 2]>::from_slice_or(input, |_| 0))
+    }
+
+    #[test]
+    fn fill_const() {
+        const fn filled<const N: usize>(e: u8) -> [u8; N] {
+            <[u8; N]>::fill(e)
+        }
+        const RESULT: [u8; 3] = filled(7);
+        assert_eq!([7, 7, 7], RESULT)
+    }
+
+    #[test]
+    fn update_at_copy() {
+        let input = [1, 2, 3, 4];
+        let expected = [1, 2, 4, 4];
+        let result = input.update_at::<2, _>(|e| e + 1);
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn position_copy() {
+        let input = [1, 2, 3, 2];
+        assert_eq!(Some(1), input.position(&2));
+        assert_eq!(None, input.position(&5));
+        assert_eq!(None, [0u8; 0].position(&5))
+    }
+
+    #[test]
+    fn contains_const() {
+        const TABLE: [u8; 4] = [0x10, 0x20, 0x7f, 0x30];
+        const CONTAINED: bool = TABLE.contains(&0x7f);
+        const POSITION: Option<usize> = TABLE.position(&0x7f);
+        const MISSING: bool = TABLE.contains(&0x00);
+        assert_eq!((true, Some(2), false), (CONTAINED, POSITION, MISSING))
+    }
+
+    #[test]
+    fn sort_copy() {
+        let input = [3, 1, 2];
+        let expected = [1, 2, 3];
+        let result = input.sort();
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn sort_const() {
+        const SORTED: [i32; 6] = [5, -1, 3, 3, 0, 9].sort();
+        assert_eq!([-1, 0, 3, 3, 5, 9], SORTED)
+    }
+
+    #[test]
+    fn partition_point_copy() {
+        let input: [u8; 8] = [1, 2, 4, 8, 16, 32, 64, 128];
+        assert_eq!(0, input.partition_point(|e| *e < 1));
+        assert_eq!(4, input.partition_point(|e| *e < 10));
+        assert_eq!(8, input.partition_point(|e| *e < 200));
+        assert_eq!(0, [0u8; 0].partition_point(|e| *e < 200))
+    }
+
+    #[test]
+    fn partition_point_const() {
+        const fn below_ten(e: &u8) -> bool {
+            *e < 10
+        }
+        const POINT: usize = [1, 2, 4, 8, 16, 32, 64, 128].partition_point(below_ten);
+        assert_eq!(4, POINT)
+    }
+
+    #[test]
+    fn for_each_chunk_copy() {
+        let mut input: [u8; 8] = [0x00, 0x0f, 0xf0, 0xff, 0x12, 0x34, 0x56, 0x78];
+        let expected = [0xde, 0xa2, 0x4e, 0x10, 0xcc, 0x99, 0xe8, 0x97];
+        input.for_each_chunk::<4, _>(|chunk| {
+            for (e, k) in chunk.iter_mut().zip([0xde, 0xad, 0xbe, 0xef]) {
+                *e ^= k
+            }
+        });
+        assert_eq!(expected, input)
+    }
+
+    #[test]
+    fn split_ref_copy() {
+        let input = [1, 2, 3, 4];
+        assert_eq!((&[], &[1, 2, 3, 4]), input.split_ref::<0>());
+        assert_eq!((&[1, 2, 3, 4], &[]), input.split_ref::<4>())
+    }
+
+    #[test]
+    fn to_tuple_copy() {
+        assert_eq!((1, 2), [1, 2].to_tuple());
+        assert_eq!((1, 2, 3), [1, 2, 3].to_tuple());
+        assert_eq!((1, 2, 3, 4), [1, 2, 3, 4].to_tuple());
+        assert_eq!([1, 2, 3, 4], <[u8; 4]>::from_tuple((1, 2, 3, 4)))
+    }
+
+    #[test]
+    fn get_and_replace_copy() {
+        let input = [1, 2, 3, 4];
+        assert_eq!(Ok(([9, 2, 3, 4], 1)), input.get_and_replace(0, 9));
+        assert_eq!(Ok(([1, 2, 3, 9], 4)), input.get_and_replace(3, 9));
+        assert_eq!(Err(([1, 2, 3, 4], 9)), input.get_and_replace(4, 9));
+        assert_eq!(Err(([1, 2, 3, 4], 9)), input.get_and_replace(usize::MAX, 9))
+    }
+
+    #[test]
+    fn cswap_copy() {
+        let input: [u64; 4] = [u64::MAX, 2, 0x0123_4567_89ab_cdef, 4];
+        assert_eq!(
+            [0x0123_4567_89ab_cdef, 2, u64::MAX, 4],
+            input.cswap::<0, 2>(true)
+        );
+        assert_eq!(input, input.cswap::<0, 2>(false));
+        assert_eq!(input, input.cswap::<1, 1>(true));
+        const SWAPPED: [i8; 3] = [i8::MIN, 0, -1].cswap::<0, 2>(true);
+        assert_eq!([-1, 0, i8::MIN], SWAPPED)
+    }
+
+    #[test]
+    fn rotate_left_by_copy() {
+        let input = [1, 2, 3, 4, 5];
+        assert_eq!([1, 2, 3, 4, 5], input.rotate_left_by(0));
+        assert_eq!([2, 3, 4, 5, 1], input.rotate_left_by(1));
+        assert_eq!([1, 2, 3, 4, 5], input.rotate_left_by(5));
+        assert_eq!([3, 4, 5, 1, 2], input.rotate_left_by(12));
+        assert_eq!([0u8; 0], [0u8; 0].rotate_left_by(3))
+    }
+
+    #[test]
+    fn dedup_consecutive_copy() {
+        let input = [1, 1, 2, 3, 3, 3];
+        let (result, count) = input.dedup_consecutive();
+        assert_eq!(3, count);
+        assert_eq!([1, 2, 3], result[..count]);
+        assert_eq!(([1, 2, 1], 3), [1, 2, 1].dedup_consecutive());
+        assert_eq!(([0u8; 0], 0), [0u8; 0].dedup_consecutive())
+    }
+
+    #[repr(align(64))]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct OverAligned(u8);
+
+    #[test]
+    fn over_aligned_layout() {
+        use crate::Contiguous;
+        use core::mem::{align_of, size_of};
+
+        type Joined = Contiguous<[OverAligned; 2], [OverAligned; 3]>;
+        assert_eq!(size_of::<[OverAligned; 5]>(), size_of::<Joined>());
+        assert_eq!(align_of::<[OverAligned; 5]>(), align_of::<Joined>());
+        assert_eq!(
+            size_of::<[OverAligned; 3]>(),
+            size_of::<Contiguous<[OverAligned; 2], OverAligned>>()
+        )
+    }
+
+    #[test]
+    fn over_aligned_copy() {
+        let input = [OverAligned(1), OverAligned(2)];
+        let expected = [1, 2, 3, 4, 5].map(OverAligned);
+        let result = input.concat([3, 4, 5].map(OverAligned));
+        assert_eq!(expected, result);
+        let result = [OverAligned(3)]
+            .concat_back(input)
+            .push_front(OverAligned(0));
+        assert_eq!([0, 1, 2, 3].map(OverAligned), result);
+        assert_eq!([3, 4, 5].map(OverAligned), expected.truncate_start::<2>());
+        assert_eq!([1, 2].map(OverAligned), expected.truncate_end::<3>());
+        for (i, e) in result.iter().enumerate() {
+            assert_eq!(
+                0,
+                e as *const OverAligned as usize % 64,
+                "element {i} is misaligned"
+            )
+        }
+    }
+
+    #[test]
+    fn scan_const_copy() {
+        let input = [1, 2, 3, 4];
+        let expected = [1, 3, 6, 10];
+        let result = input.scan_const(0, |acc, e| acc + e);
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn scan_const_const() {
+        const fn running_max(acc: u8, e: &u8) -> u8 {
+            if *e > acc {
+                *e
+            } else {
+                acc
+            }
+        }
+        const RESULT: [u8; 5] = [1, 3, 2, 5, 4].scan_const(0, running_max);
+        assert_eq!([1, 3, 3, 5, 5], RESULT)
+    }
+
+    #[test]
+    fn keep_ends_copy() {
+        let input = [1, 2, 3, 4, 5, 6];
+        assert_eq!([1, 2, 6], input.keep_ends::<2, 1>());
+        assert_eq!([4, 5, 6], input.keep_ends::<0, 3>());
+        assert_eq!(input, input.keep_ends::<4, 2>())
+    }
+
+    #[test]
+    fn try_map_each_copy() {
+        let input = [1i32, 2, -3, 4];
+        assert!(input.try_map_each(u8::try_from).is_err());
+        assert_eq!(
+            Ok([1u8, 2, 3, 4]),
+            input.map(i32::abs).try_map_each(u8::try_from)
+        );
+        const fn halve(e: u8) -> Result<u8, u8> {
+            if e & 1 == 0 {
+                Ok(e / 2)
+            } else {
+                Err(e)
+            }
+        }
+        const RESULT: Result<[u8; 3], u8> = [2, 4, 6].try_map_each(halve);
+        assert_eq!(Ok([1, 2, 3]), RESULT);
+        const ERR: Result<[u8; 3], u8> = [2, 5, 6].try_map_each(halve);
+        assert_eq!(Err(5), ERR)
+    }
+
+    #[test]
+    fn group_pairs_copy() {
+        let input = [1, 2, 3, 4, 5, 6];
+        let expected = [[1, 2], [3, 4], [5, 6]];
+        let result = input.group_pairs();
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn gather_copy() {
+        let input = [10, 20, 30, 40];
+        assert_eq!([40, 30, 20, 10], input.gather([3, 2, 1, 0]));
+        assert_eq!([20, 20, 20], input.gather([1, 1, 1]));
+        assert_eq!([0u8; 0], input.gather([]))
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn gather_out_of_bounds_copy() {
+        [10, 20, 30, 40].gather([4]);
+    }
+
+    #[test]
+    fn scatter_copy() {
+        let input = [1, 2, 3, 4];
+        assert_eq!([4, 3, 2, 1], input.scatter([0, 1, 2, 3], [4, 3, 2, 1]));
+        assert_eq!([1, 2, 3, 4], input.scatter([], []));
+        let permutation = [2, 0, 3, 1];
+        let shuffled = input.gather(permutation);
+        assert_eq!(input, [0; 4].scatter(permutation, shuffled));
+    }
+
+    #[test]
+    fn scatter_repeated_copy() {
+        let input = [1, 2, 3, 4];
+        assert_eq!([1, 7, 3, 4], input.scatter([1, 1, 1], [5, 6, 7]));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn scatter_out_of_bounds_copy() {
+        [1, 2, 3, 4].scatter([4], [0]);
+    }
+
+    #[test]
+    fn enumerate_const_copy() {
+        const RESULT: [(usize, u8); 3] = [30, 10, 20].enumerate_const();
+        assert_eq!([(0, 30), (1, 10), (2, 20)], RESULT);
+        assert_eq!(0, [0u8; 0].enumerate_const().len());
+    }
+
+    #[test]
+    fn argsort_copy() {
+        const RESULT: [usize; 3] = [30, 10, 20].argsort();
+        assert_eq!([1, 2, 0], RESULT);
+        let input = [5, 3, 5, 1];
+        assert_eq!(input.sort(), input.gather(input.argsort()));
+        assert_eq!([0usize; 0], [0u8; 0].argsort());
+    }
+
+    #[test]
+    fn min_max_const_copy() {
+        const fn distance_to_ten(e: &u8) -> u8 {
+            e.abs_diff(10)
+        }
+        const INPUT: [u8; 5] = [7, 12, 3, 12, 9];
+        const MIN: Option<&u8> = INPUT.min_const();
+        const MAX: Option<&u8> = INPUT.max_const();
+        const CLOSEST: Option<&u8> = INPUT.min_by_key_const(distance_to_ten);
+        assert_eq!((Some(&3), Some(&12), Some(&9)), (MIN, MAX, CLOSEST));
+        let input = INPUT;
+        assert!(std::ptr::eq(&input[3], input.max_const().unwrap())); // the last greatest
+        assert_eq!((None, None), ([0u8; 0].min_const(), [0u8; 0].max_const()));
+    }
+
+    #[test]
+    fn fold_const_copy() {
+        const fn checksum(acc: u32, e: u8) -> u32 {
+            acc.rotate_left(5) ^ e as u32
+        }
+        const fn sum(acc: u8, e: u8) -> u8 {
+            acc.wrapping_add(e)
+        }
+        const BUFFER: [u8; 4] = [0xde, 0xad, 0xbe, 0xef];
+        const CHECKSUM: u32 = BUFFER.fold_const(0, checksum);
+        const SUM: Option<u8> = BUFFER.reduce_const(sum);
+        assert_eq!(BUFFER.iter().fold(0, |acc, e| checksum(acc, *e)), CHECKSUM);
+        assert_eq!(Some(0x38), SUM);
+        assert_eq!(10, [1, 2, 3, 4].fold_const(0, |acc, e| acc + e));
+        assert_eq!(None, [0u8; 0].reduce_const(sum));
+    }
+
+    #[test]
+    fn copy_within_const_copy() {
+        let input = [1, 2, 3, 4, 5, 6];
+        assert_eq!([5, 6, 3, 4, 5, 6], input.copy_within_const::<4, 2, 0>()); // disjoint
+        assert_eq!([1, 1, 2, 3, 4, 6], input.copy_within_const::<0, 4, 1>()); // overlapping forwards
+        assert_eq!([2, 3, 4, 5, 5, 6], input.copy_within_const::<1, 4, 0>()); // & backwards
+        assert_eq!(input, input.copy_within_const::<6, 0, 6>());
+        const RESULT: [u8; 4] = [1, 2, 3, 4].copy_within_const::<0, 2, 2>();
+        assert_eq!([1, 2, 1, 2], RESULT);
+    }
+
+    #[cfg(feature = "hex")]
+    #[test]
+    fn write_hex_copy() {
+        use crate::ArrayHex;
+        use core::fmt::Write;
+
+        // a fixed-capacity buffer, like the ones used on embedded targets
+        struct Buffer<const CAP: usize> {
+            bytes: [u8; CAP],
+            len: usize,
+        }
+
+        impl<const CAP: usize> Write for Buffer<CAP> {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                let end = self.len + s.len();
+                self.bytes
+                    .get_mut(self.len..end)
+                    .ok_or(core::fmt::Error)?
+                    .copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
+
+        let input = [0x00, 0x0f, 0xa0, 0xff];
+        let mut buffer = Buffer {
+            bytes: [0; 8],
+            len: 0,
+        };
+        assert_eq!(Ok(()), input.write_hex(&mut buffer));
+        assert_eq!(b"000fa0ff", &buffer.bytes);
+        assert_eq!(
+            Err(core::fmt::Error),
+            input.write_hex(&mut Buffer {
+                bytes: [0; 7],
+                len: 0
+            })
+        );
+        assert_eq!("000fa0ff", format!("{}", input.hex()));
+        assert_eq!("", [0u8; 0].hex().to_string());
+    }
 
     #[test]
-    fn append_noncopy() {
-        let input = [vec![1, 2], vec![3, 4]];
-        let expected = [vec![1, 2], vec![3, 4], vec![5, 6, 7]];
-        let result = input.append(vec![5, 6, 7]);
-        assert_eq!(expected, result)
+    fn concat_map_copy() {
+        let input: [i16; 3] = [-300, 0, 300];
+        let expected: [i16; 5] = [-300, 0, 300, -128, 127];
+        let result = input.concat_map([i8::MIN, i8::MAX]);
+        assert_eq!(expected, result);
+        assert_eq!(input, input.concat_map::<i8, 0>([]));
     }
 
     #[test]
-    fn append_back_noncopy() {
-        let input = [vec![1, 2], vec![3, 4]];
-        let expected = [vec![254, 255, 0], vec![1, 2], vec![3, 4]];
-        let result = input.append_back(vec![254, 255, 0]);
-        assert_eq!(expected, result)
+    fn pipe_copy() {
+        const fn frame(payload: [u8; 2]) -> [u8; 4] {
+            payload.push_front(0x02).push_back(0x03)
+        }
+        const RESULT: [u8; 4] = [1, 2].pipe(frame);
+        assert_eq!([0x02, 1, 2, 0x03], RESULT);
     }
 
     #[test]
-    fn concat_noncopy() {
-        let input = [vec![1, 2], vec![3, 4]];
-        let expected = [
-            vec![1, 2],
-            vec![3, 4],
-            vec![5, 6, 7],
-            vec![8, 9],
-            vec![10, 11, 12],
-        ];
-        let result = input.concat([vec![5, 6, 7], vec![8, 9], vec![10, 11, 12]]);
-        assert_eq!(expected, result)
+    fn split_mut_copy() {
+        let mut input = [0u16; 1024];
+        let (left, right) = input.split_mut::<300>();
+        std::thread::scope(|s| {
+            s.spawn(|| left.fill(1));
+            s.spawn(|| right.fill(2));
+        });
+        assert_eq!(
+            (300, 724),
+            (
+                input.iter().filter(|e| **e == 1).count(),
+                input.iter().filter(|e| **e == 2).count()
+            )
+        );
+        assert_eq!([1, 2], [input[299], input[300]]);
+        let (empty, all) = input.split_mut::<0>();
+        assert_eq!((0, 1024), (empty.len(), all.len()))
     }
 
     #[test]
-    fn concat_back_noncopy() {
-        let input = [vec![1, 2], vec![3, 4]];
-        let expected = [
-            vec![249, 250, 251],
-            vec![252, 253],
-            vec![254, 255, 0],
-            vec![1, 2],
-            vec![3, 4],
-        ];
-        let result = input.concat_back([vec![249, 250, 251], vec![252, 253], vec![254, 255, 0]]);
-        assert_eq!(expected, result)
+    fn rchunks_copy() {
+        let input = [1, 2, 3, 4, 5, 6];
+        assert_eq!([[4, 5, 6], [1, 2, 3]], input.rchunks::<3>());
+        assert_eq!([[6], [5], [4], [3], [2], [1]], input.rchunks::<1>());
+        assert_eq!([input], input.rchunks::<6>())
     }
 
     #[test]
-    fn truncate_start_noncopy() {
-        let input = [vec![1, 2], vec![3, 4], vec![5, 6], vec![7, 8]];
-        let expected = [vec![5, 6], vec![7, 8]];
-        let result = input.truncate_start::<2>();
-        assert_eq!(expected, result)
+    fn chunks_with_remainder_copy() {
+        let input = [1, 2, 3, 4, 5];
+        assert_eq!(([[1, 2, 3, 4, 5]], []), input.chunks_with_remainder::<5>());
+        assert_eq!(([], [1, 2, 3, 4, 5]), input.chunks_with_remainder::<6>());
+        assert_eq!(([[1, 2], [3, 4]], [5]), input.chunks_with_remainder::<2>());
+        const RESULT: ([[u8; 2]; 1], [u8; 1]) = [1, 2, 3].chunks_with_remainder::<2>();
+        assert_eq!(([[1, 2]], [3]), RESULT)
     }
 
     #[test]
-    fn truncate_end_noncopy() {
-        let input = [vec![1, 2], vec![3, 4], vec![5, 6], vec![7, 8]];
-        let expected = [vec![1, 2], vec![3, 4]];
-        let result = input.truncate_end::<2>();
-        assert_eq!(expected, result)
+    fn fnv1a_copy() {
+        const EMPTY: u64 = [].fnv1a();
+        assert_eq!(0xcbf29ce484222325, EMPTY);
+        assert_eq!(0xaf63dc4c8601ec8c, b"a".fnv1a());
+        assert_eq!(0x85944171f73967e8, b"foobar".fnv1a());
+        assert_ne!(b"ab".fnv1a(), b"ba".fnv1a())
     }
 
     #[test]
-    fn append_copy() {
-        let input = [1, 2, 3, 4];
-        let expected = [1, 2, 3, 4, 5];
-        let result = input.append(5);
-        assert_eq!(expected, result)
+    fn bitwise_copy() {
+        let (a, b): ([u8; 4], [u8; 4]) = ([0xde, 0xad, 0xbe, 0xef], [0x0f, 0xf0, 0xff, 0x00]);
+        assert_eq!([0xd1, 0x5d, 0x41, 0xef], a.xor(b));
+        assert_eq!([0x0e, 0xa0, 0xbe, 0x00], a.and(b));
+        assert_eq!([0xdf, 0xfd, 0xff, 0xef], a.or(b));
+        assert_eq!([0; 4], a.xor(a));
+        assert_eq!(a, a.xor(b).xor(b));
+        assert_eq!((a, a), (a.and(a), a.or(a)));
+        const MASKED: [u16; 2] = [0x1234, 0xabcd].and([0xff00, 0x00ff]);
+        assert_eq!([0x1200, 0x00cd], MASKED);
+        assert_eq!([true, false], [true, true].xor([false, true]))
     }
 
     #[test]
-    fn append_back_copy() {
-        let input = [1, 2, 3, 4];
-        let expected = [0, 1, 2, 3, 4];
-        let result = input.append_back(0);
-        assert_eq!(expected, result)
+    fn const_vec_copy() {
+        let mut vec = ConstVec::<u8, 256>::new();
+        assert!(vec.is_empty());
+        let mut i = 0;
+        while vec.push(i as u8).is_ok() {
+            i += 1;
+        }
+        assert_eq!((256, 256), (i, vec.len()));
+        let expected = core::array::from_fn(|i| i as u8);
+        assert_eq!(
+            Ok(expected),
+            vec.into_array::<256>().map_err(|vec| vec.len())
+        );
+        assert_eq!(
+            Err(1),
+            ConstVec::<u8, 1>::new()
+                .into_array::<1>()
+                .map_err(|vec| vec.len() + 1)
+        );
     }
 
     #[test]
-    fn concat_copy() {
-        let input = [1, 2, 3, 4];
-        let expected = [1, 2, 3, 4, 5, 6, 7];
-        let result = input.concat([5, 6, 7]);
-        assert_eq!(expected, result)
+    fn compact_copy() {
+        const fn is_even(e: &u8) -> bool {
+            e.is_multiple_of(2)
+        }
+        const RESULT: ([u8; 6], usize) = [1, 2, 3, 4, 5, 6].compact(is_even);
+        assert_eq!(3, RESULT.1);
+        assert_eq!([2, 4, 6], RESULT.0[..RESULT.1]);
+        assert_eq!(([1, 2, 3], 0), [1, 2, 3].compact(|_| false));
+        assert_eq!(([1, 2, 3], 3), [1, 2, 3].compact(|_| true));
+        assert_eq!(([0u8; 0], 0), [].compact(is_even))
     }
 
     #[test]
-    fn concat_back_copy() {
+    fn binary_search_copy() {
+        const TABLE: [u8; 8] = [2, 3, 5, 7, 11, 13, 17, 19];
+        const FOUND: Result<usize, usize> = TABLE.binary_search(&11);
+        assert_eq!(Ok(4), FOUND);
+        let mut i = 0;
+        while i <= 20 {
+            assert_eq!(TABLE[..].binary_search(&i), TABLE.binary_search(&i));
+            i += 1;
+        }
+        let duplicated = [1, 2, 2, 2, 3];
+        assert!(matches!(duplicated.binary_search(&2), Ok(1..=3)));
+        assert_eq!(Err(0), [0u8; 0].binary_search(&1))
+    }
+
+    #[test]
+    fn rotate_to_front_copy() {
+        let mut ring = [0u8; 4];
+        let mut i = 0;
+        while i < 6 {
+            ring[i % 4] = i as u8; // overwrite the oldest once full
+            i += 1;
+        }
+        let head = i % 4; // the oldest element
+        assert_eq!([4, 5, 2, 3], ring);
+        assert_eq!([2, 3, 4, 5], ring.rotate_to_front(head));
+        assert_eq!(ring.rotate_to_front(head), ring.rotate_to_front(head + 4));
+        assert_eq!(ring, ring.rotate_to_front(0));
+        assert_eq!([0u8; 0], [].rotate_to_front(3))
+    }
+
+    #[test]
+    fn concat_arrays_copy() {
+        let (a, b, c, d) = ([1], [2, 3], [4, 5, 6], [7, 8, 9, 10]);
+        let result: [u8; 10] = concat_arrays!(a, b, c, d);
+        assert_eq!([1, 2, 3, 4, 5, 6, 7, 8, 9, 10], result);
+        assert_eq!(a, concat_arrays!(a));
+        assert_eq!(b, concat_arrays!([], b, []));
+        const JOINED: [u8; 3] = concat_arrays!([1], [2], [3]);
+        assert_eq!([1, 2, 3], JOINED)
+    }
+
+    #[test]
+    fn u32_array_copy() {
+        let input: [u8; 8] = [0xde, 0xad, 0xbe, 0xef, 0x01, 0x23, 0x45, 0x67];
+        assert_eq!([0xefbeadde, 0x67452301], input.to_u32_array_le());
+        assert_eq!([0xdeadbeef, 0x01234567], input.to_u32_array_be());
+        assert_eq!(input, <[u8; 8]>::from_u32_array_le(input.to_u32_array_le()));
+        assert_eq!(input, <[u8; 8]>::from_u32_array_be(input.to_u32_array_be()));
+        const WORDS: [u32; 1] = [1, 0, 0, 0].to_u32_array_le();
+        assert_eq!([1], WORDS);
+        assert_eq!([0u32; 0], [].to_u32_array_be())
+    }
+
+    #[test]
+    fn insert_sorted_copy() {
+        let input = [1, 2, 4, 5];
+        assert_eq!([1, 2, 3, 4, 5], input.insert_sorted(3));
+        assert_eq!([0, 1, 2, 4, 5], input.insert_sorted(0));
+        assert_eq!([1, 2, 4, 5, 6], input.insert_sorted(6));
+        const TABLE: [u8; 3] = [].insert_sorted(5).insert_sorted(1).insert_sorted(3);
+        assert_eq!([1, 3, 5], TABLE);
+
+        // compares only the key, to check equal keys keep their order
+        #[derive(Clone, Copy, Debug)]
+        struct Key(u8, char);
+        impl PartialEq for Key {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        impl Eq for Key {}
+        impl PartialOrd for Key {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for Key {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+        let input = [Key(1, 'a'), Key(2, 'a'), Key(2, 'b'), Key(3, 'a')];
+        let result = input.insert_sorted(Key(2, 'c')).map(|e| e.1);
+        assert_eq!(['a', 'a', 'b', 'c', 'a'], result)
+    }
+
+    #[test]
+    fn split_by_copy() {
+        const fn is_even(e: &u8) -> bool {
+            e.is_multiple_of(2)
+        }
+        const RESULT: ([u8; 6], [u8; 6], usize, usize) = [1, 2, 3, 4, 5, 6].split_by(is_even);
+        let (kept, removed, kept_count, removed_count) = RESULT;
+        assert_eq!((3, 3), (kept_count, removed_count));
+        assert_eq!([2, 4, 6], kept[..kept_count]);
+        assert_eq!([1, 3, 5], removed[..removed_count]);
+        let (kept, _, kept_count, removed_count) = [1, 2, 3].split_by(|_| true);
+        assert_eq!(([1, 2, 3], 3, 0), (kept, kept_count, removed_count));
+        assert_eq!(([0u8; 0], [0u8; 0], 0, 0), [].split_by(is_even))
+    }
+
+    #[test]
+    fn windows_map_copy() {
+        const fn sum(window: &[u8; 3]) -> u8 {
+            window[0] + window[1] + window[2]
+        }
+        const RESULT: [u8; 3] = [1, 2, 3, 4, 5].windows_map(sum);
+        assert_eq!([6, 9, 12], RESULT);
+        let input = [1, 2, 3, 4, 5];
+        assert_eq!(
+            input.windows::<2>().map(|w| w[1] - w[0]),
+            input.windows_map(|w: &[u8; 2]| w[1] - w[0])
+        );
+        assert_eq!(
+            [vec![1, 2, 3, 4, 5]],
+            input.windows_map(|w: &[u8; 5]| w.to_vec())
+        )
+    }
+
+    #[test]
+    fn build_copy() {
+        const fn square(i: usize) -> usize {
+            i * i
+        }
+        const fn small(i: usize) -> Result<u8, usize> {
+            if i < 3 {
+                Ok(i as u8)
+            } else {
+                Err(i)
+            }
+        }
+        const SQUARES: [usize; 4] = <[usize; 4]>::build(square);
+        const SMALL: Result<[u8; 3], usize> = <[u8; 3]>::try_build(small);
+        const TOO_BIG: Result<[u8; 5], usize> = <[u8; 5]>::try_build(small);
+        assert_eq!([0, 1, 4, 9], SQUARES);
+        assert_eq!((Ok([0, 1, 2]), Err(3)), (SMALL, TOO_BIG));
+        assert_eq!(
+            core::array::from_fn::<_, 8, _>(|i| i * 3),
+            <[usize; 8]>::build(|i| i * 3)
+        );
+        assert_eq!([0u8; 0], <[u8; 0]>::build(|_| unreachable!()))
+    }
+
+    #[test]
+    fn reflect_pad_copy() {
+        let input = [1, 2, 3];
+        assert_eq!([1, 1, 2, 3, 3], input.reflect_pad::<1>());
+        assert_eq!([3, 2, 1, 1, 2, 3, 3, 2, 1], input.reflect_pad::<3>());
+        assert_eq!(input, input.reflect_pad::<0>());
+        assert_eq!(0, [0u8; 0].reflect_pad::<0>().len());
+        const PADDED: [u8; 6] = [1, 2, 3, 4].reflect_pad::<1>();
+        assert_eq!([1, 1, 2, 3, 4, 4], PADDED)
+    }
+
+    #[test]
+    fn edge_pad_copy() {
+        let input = [1, 2, 3];
+        assert_eq!([1, 1, 2, 3, 3], input.edge_pad::<1>());
+        assert_eq!([1, 1, 1, 1, 2, 3, 3, 3, 3], input.edge_pad::<3>());
+        assert_eq!([7, 7, 7], [7].edge_pad::<1>());
+        assert_eq!(input, input.edge_pad::<0>())
+    }
+
+    #[test]
+    fn count_copy() {
+        const fn is_zero(e: &u8) -> bool {
+            *e == 0
+        }
+        const TABLE: [u8; 6] = [3, 0, 7, 1, 9, 4];
+        const ZEROS: usize = TABLE.count(is_zero);
+        const EMPTY: usize = [].count(is_zero);
+        assert_eq!((1, 0), (ZEROS, EMPTY));
+        assert_eq!(3, TABLE.count(|e| *e > 3))
+    }
+
+    #[test]
+    fn all_any_copy() {
+        const fn is_nonzero(e: &u8) -> bool {
+            *e != 0
+        }
+        const TABLE: [u8; 4] = [3, 1, 4, 1];
+        const ALL: bool = TABLE.all(is_nonzero);
+        const ANY: bool = [0, 0, 5].any(is_nonzero);
+        const NONE: bool = [0, 0].any(is_nonzero);
+        assert_eq!((true, true, false), (ALL, ANY, NONE));
+        assert_eq!((true, false), ([].all(is_nonzero), [].any(is_nonzero)))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn concat_boxed_copy() {
+        use crate::ArrayBoxed;
+
+        // each array alone is half of the 2MiB stack of the test threads
+        let input: Box<[u8; 1_000_000]> = vec![1; 1_000_000].try_into().unwrap();
+        let array: Box<[u8; 1_000_000]> = vec![2; 1_000_000].try_into().unwrap();
+        let result = input.concat_boxed(array);
+        assert_eq!(2_000_000, result.len());
+        assert_eq!(
+            (1, 1, 2, 2),
+            (
+                result[0],
+                result[999_999],
+                result[1_000_000],
+                result[1_999_999]
+            )
+        );
+        assert_eq!(Box::new([1]), Box::new([]).concat_boxed(Box::new([1])))
+    }
+
+    #[test]
+    fn map_each_copy() {
+        const HEADER: [u16; 2] = [0x0102u16, 0x0304].map_each(u16::swap_bytes);
+        assert_eq!([0x0201, 0x0403], HEADER);
+        let words = [0x12345678u32, 0x9abcdef0];
+        assert_eq!(words.map(u32::swap_bytes), words.map_each(u32::swap_bytes));
+        assert_eq!(
+            words,
+            words.map_each(u32::swap_bytes).map_each(u32::swap_bytes)
+        )
+    }
+
+    #[test]
+    fn positions_copy() {
+        const fn is_zero(e: &u8) -> bool {
+            *e == 0
+        }
+        const ZEROS: ([usize; 4], usize) = [0, 1, 0, 2, 0].positions(is_zero);
+        assert_eq!(([0, 2, 4, 0], 3), ZEROS);
+        assert_eq!(([0, 2], 3), [0, 1, 0, 2, 0].positions(is_zero)); // truncated
+        assert_eq!(([], 3), [0, 1, 0, 2, 0].positions::<0, _>(is_zero));
+        assert_eq!(([0; 2], 0), [].positions(is_zero))
+    }
+
+    #[test]
+    fn concat_overlap_copy() {
+        let input = [1, 2, 3];
+        assert_eq!([1, 2, 3, 4, 5], input.concat_overlap([3, 4, 5]));
+        assert_eq!(input, input.concat_overlap([3]));
+        assert_eq!([3, 4], [3].concat_overlap([3, 4]));
+        const PATH: [(u8, u8); 4] = [(0, 0), (1, 0)].concat_overlap([(1, 0), (1, 1), (0, 1)]);
+        assert_eq!([(0, 0), (1, 0), (1, 1), (0, 1)], PATH)
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "the boundary elements must be equal")]
+    fn concat_overlap_mismatch_copy() {
+        [1, 2, 3].concat_overlap([4, 5]);
+    }
+
+    #[test]
+    fn eq_by_copy() {
+        const fn within_one(a: &u8, b: &u8) -> bool {
+            a.abs_diff(*b) <= 1
+        }
+        const fn equal(a: &u8, b: &u8) -> bool {
+            *a == *b
+        }
+        const APPROX: bool = [1, 2, 3].eq_by(&[1, 2, 4], within_one);
+        const EXACT: bool = [1, 2, 3].eq_by(&[1, 2, 4], equal);
+        assert_eq!((true, false), (APPROX, EXACT));
+        assert!(!([1, 2, 3].eq_by(&[3, 2, 1], within_one)));
+        assert!([].eq_by(&[], equal))
+    }
+
+    #[test]
+    fn fold_chunks_copy() {
+        const fn add_pair(acc: u8, chunk: &[u8; 2]) -> u8 {
+            acc + chunk[0] + chunk[1]
+        }
+        const SUM: u8 = [1, 2, 3, 4].fold_chunks(0, add_pair);
+        assert_eq!(10, SUM);
+        let words = [0xde, 0xad, 0xbe, 0xef];
+        let checksum = words.fold_chunks::<2, u16, _>(0, |acc, c| acc ^ u16::from_be_bytes(*c));
+        assert_eq!(0xdead ^ 0xbeef, checksum);
+        assert_eq!(
+            vec![[1], [2]],
+            [1, 2].fold_chunks(vec![], |mut acc, c: &[u8; 1]| {
+                acc.push(*c);
+                acc
+            })
+        )
+    }
+
+    #[test]
+    fn partition_around_copy() {
+        let (result, split) = [3, 1, 4, 1, 5].partition_around(3);
+        assert_eq!(2, split);
+        assert_eq!([1, 1], result[..split]);
+        let mut rest = result[split..].to_vec();
+        rest.sort();
+        assert_eq!(vec![3, 4, 5], rest);
+        assert_eq!(([1, 2], 0), [1, 2].partition_around(0));
+        assert_eq!(([1, 2], 2), [1, 2].partition_around(9));
+        const RESULT: ([u8; 4], usize) = [9, 2, 7, 4].partition_around(5);
+        assert_eq!(([2, 4, 7, 9], 2), RESULT)
+    }
+
+    #[test]
+    fn select_nth_copy() {
+        const MEDIAN: (u8, [u8; 5]) = [5, 3, 1, 4, 2].select_nth(2);
+        assert_eq!(3, MEDIAN.0);
+        let input = [9, 1, 8, 2, 7, 3, 3, 6, 4, 5];
+        let sorted = input.sort();
+        let mut k = 0;
+        while k < input.len() {
+            let (e, result) = input.select_nth(k);
+            assert_eq!(sorted[k], e);
+            assert_eq!(e, result[k]);
+            assert!(result[..k].iter().all(|x| *x <= e) && result[k..].iter().all(|x| *x >= e));
+            k += 1;
+        }
+        assert_eq!((7, [7; 3]), [7; 3].select_nth(1))
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn select_nth_out_of_bounds_copy() {
+        [1, 2, 3].select_nth(3);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn poisoned_copy() {
+        use crate::poisoned;
+
+        let mut result = poisoned::<[u16; 3]>();
+        let out = result.as_mut_ptr().cast::<u16>();
+        unsafe {
+            // deliberately skip the last element, like an off-by-one would
+            out.write(1);
+            out.add(1).write(2);
+            assert_eq!([1, 2, 0xfafa], result.assume_init())
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "element left unwritten")]
+    fn assume_filled_copy() {
+        use crate::{assume_filled, poisoned};
+        use core::mem::MaybeUninit;
+
+        let mut result = poisoned::<[u16; 4]>();
+        // an off-by-one length, so the real concat_into fills an element less
+        let out = unsafe { &mut *result.as_mut_ptr().cast::<MaybeUninit<[u16; 3]>>() };
+        [1, 2].concat_into([3], out);
+        unsafe { assume_filled(result) };
+    }
+
+    #[test]
+    fn split_off_start_copy() {
         let input = [1, 2, 3, 4];
-        let expected = [254, 255, 0, 1, 2, 3, 4];
-        let result = input.concat_back([254, 255, 0]);
-        assert_eq!(expected, result)
+        assert_eq!(([], [1, 2, 3, 4]), input.split_off_start::<0>());
+        assert_eq!(([1, 2, 3, 4], []), input.split_off_start::<4>());
+        const PARTS: ([u8; 2], [u8; 2]) = [1, 2, 3, 4].split_off_start::<2>();
+        assert_eq!(([1, 2], [3, 4]), PARTS)
     }
 
     #[test]
-    fn truncate_start_copy() {
+    fn rotate_left_inplace_copy() {
+        let input = [1, 2, 3, 4, 5, 6];
+        macro_rules! check {
+            ($($k:literal),*) => {$(
+                let mut result = input;
+                result.rotate_left_inplace::<$k>();
+                assert_eq!(input.rotate_left_by($k), result);
+            )*};
+        }
+        check!(0, 1, 2, 3, 4, 5, 6, 9);
+        let mut empty = [0u8; 0];
+        empty.rotate_left_inplace::<3>();
+        const ROTATED: [u8; 4] = {
+            let mut array = [1, 2, 3, 4];
+            array.rotate_left_inplace::<1>();
+            array
+        };
+        assert_eq!([2, 3, 4, 1], ROTATED)
+    }
+
+    #[test]
+    fn extend_by_copy() {
+        const RESULT: [u8; 5] = [1, 2, 3].extend_by([4, 5]);
+        assert_eq!([1, 2, 3, 4, 5], RESULT);
+        assert_eq!([1, 2, 3], [1, 2, 3].extend_by([]))
+    }
+
+    #[test]
+    fn dedup_sorted_copy() {
+        let (result, count) = [1, 1, 2, 2, 2, 3].dedup_sorted();
+        assert_eq!(3, count);
+        assert_eq!([1, 2, 3], result[..count]);
+        assert_eq!(([7], 1), [7].dedup_sorted());
+        assert_eq!(([0u8; 0], 0), [0u8; 0].dedup_sorted());
+        const TABLE: ([u8; 4], usize) = [4, 2, 4, 2].sort().dedup_sorted();
+        assert_eq!([2, 4], TABLE.0[..TABLE.1])
+    }
+
+    #[test]
+    fn interleave_channels_copy() {
+        const FRAMES: [u8; 6] = interleave_channels([[1, 4], [2, 5], [3, 6]]);
+        assert_eq!([1, 2, 3, 4, 5, 6], FRAMES);
+        assert_eq!([1, 2, 3], interleave_channels([[1, 2, 3]]));
+        assert_eq!([1, 2], interleave_channels([[1], [2]]));
+        assert_eq!([0u8; 0], interleave_channels::<u8, 0, 4>([]))
+    }
+
+    #[test]
+    fn checksum_copy() {
+        const EMPTY: (bool, u8) = ([].parity(), [].wrapping_sum());
+        assert_eq!((false, 0), EMPTY);
+        const FOOBAR: (bool, u8) = (b"foobar".parity(), b"foobar".wrapping_sum());
+        assert_eq!((false, 0x79), FOOBAR);
+        assert_eq!(
+            (true, 0),
+            ([0xff, 0x01].parity(), [0xff, 0x01].wrapping_sum())
+        );
+        assert_eq!(
+            (false, 0x06),
+            ([0x03, 0x03].parity(), [0x03, 0x03].wrapping_sum())
+        )
+    }
+
+    #[test]
+    fn concat_pointers_copy() {
+        // meant for `cargo miri test`, which tracks the provenance of every
+        // pointer through the bitwise copy of the whole array
+        let (a, b, mut c) = (1u8, [2u8, 3], 4u8);
+        let head: [*const u8; 2] = [&a, b.as_ptr()];
+        let tail: [*const u8; 2] = [unsafe { b.as_ptr().add(1) }, &raw mut c];
+        let joined = head.concat(tail);
+        assert_eq!([1, 2, 3, 4], joined.map(|p| unsafe { *p }));
+        let (last, rest) = joined.split_last();
+        unsafe { last.cast_mut().write(5) };
+        assert_eq!(5, c);
+        assert_eq!([1, 2, 3], rest.map(|p| unsafe { *p }))
+    }
+
+    #[test]
+    fn take_while_count_copy() {
+        const fn is_set(e: &u8) -> bool {
+            *e != 0
+        }
+        const HEADER: usize = [1, 2, 3, 0, 4].take_while_count(is_set);
+        const REST: usize = [1, 2, 3, 0, 4].skip_while_count(is_set);
+        assert_eq!((3, 2), (HEADER, REST));
+        assert_eq!(
+            (3, 0),
+            (
+                [1, 2, 3].take_while_count(is_set),
+                [1, 2, 3].skip_while_count(is_set)
+            )
+        );
+        assert_eq!(
+            (0, 2),
+            (
+                [0, 1].take_while_count(is_set),
+                [0, 1].skip_while_count(is_set)
+            )
+        );
+        assert_eq!(
+            (0, 0),
+            ([].take_while_count(is_set), [].skip_while_count(is_set))
+        )
+    }
+
+    #[test]
+    fn map_chunks_copy() {
+        const fn rotate(chunk: [u8; 4]) -> [u8; 4] {
+            [chunk[1], chunk[2], chunk[3], chunk[0]]
+        }
+        const RESULT: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8].map_chunks(rotate);
+        assert_eq!([2, 3, 4, 1, 6, 7, 8, 5], RESULT);
+        assert_eq!(
+            [2u16, 4],
+            [1u8, 2].map_chunks(|[e]: [u8; 1]| [e as u16 * 2])
+        );
+        assert_eq!([0u8; 0], [0u8; 0].map_chunks(rotate))
+    }
+
+    #[test]
+    fn cummax_copy() {
+        const PEAKS: [u8; 5] = [1, 3, 2, 5, 4].cummax();
+        const TROUGHS: [u8; 5] = [1, 3, 2, 5, 4].cummin();
+        assert_eq!(([1, 3, 3, 5, 5], [1, 1, 1, 1, 1]), (PEAKS, TROUGHS));
+        assert_eq!([5, 4, 3], [5, 4, 3].cummin());
+        assert_eq!([5, 5, 5], [5, 4, 3].cummax());
+        assert_eq!([0u8; 0], [0u8; 0].cummax())
+    }
+
+    #[test]
+    fn append_from_iter_copy() {
+        assert_eq!(Some([1, 2, 3]), [1].append_from_iter::<_, 2>(vec![2, 3]));
+        assert_eq!(Some([1]), [1].append_from_iter::<_, 0>(Vec::new()));
+        assert_eq!(Some([1, 2]), [].append_from_iter::<_, 2>(1..));
+        assert_eq!(None, [1].append_from_iter::<_, 1>(Vec::new()))
+    }
+
+    #[test]
+    fn starts_with_copy() {
+        const TAG: bool = b"RIFF....WAVE".starts_with(b"RIFF");
+        const KIND: bool = b"RIFF....WAVE".ends_with(b"WAVE");
+        assert_eq!((true, true), (TAG, KIND));
+        let input = [1, 2, 3];
+        assert!(input.starts_with(&[1, 2, 3]) && input.ends_with(&[1, 2, 3]));
+        assert!(input.starts_with(&[]) && input.ends_with(&[]));
+        assert!(!input.starts_with(&[2]) && !input.ends_with(&[2]));
+        assert!(!input.starts_with(&[1, 3]) && !input.ends_with(&[1, 3]))
+    }
+
+    #[test]
+    fn find_copy() {
+        const MARKER: Option<usize> = [0, 7, 7, 1, 7, 7].find(&[7, 7]);
+        assert_eq!(Some(1), MARKER);
         let input = [1, 2, 3, 4];
-        let expected = [3, 4];
-        let result = input.truncate_start::<2>();
-        assert_eq!(expected, result)
+        assert_eq!(None, input.find(&[2, 4]));
+        assert_eq!(Some(2), input.find(&[3, 4]));
+        assert_eq!(Some(0), input.find(&[1, 2, 3, 4]));
+        assert_eq!(None, input.find(&[1, 2, 3, 5]));
+        assert_eq!(Some(0), input.find(&[]));
+        assert_eq!(Some(0), [0u8; 0].find(&[]))
     }
 
     #[test]
-    fn truncate_end_copy() {
+    fn replace_copy() {
+        let input = [1, 2, 3, 4, 5];
+        assert_eq!([1, 8, 9, 4, 5], input.replace(1, [8, 9]));
+        assert_eq!([1, 2, 3, 8, 9], input.replace(3, [8, 9]));
+        assert_eq!(input, input.replace(5, []));
+        const PATCHED: [u8; 3] = [1, 2, 3].replace(0, [4, 5, 6]);
+        assert_eq!([4, 5, 6], PATCHED);
+        let overflow = std::panic::catch_unwind(|| input.replace(usize::MAX, [0]));
+        assert!(overflow.is_err());
+        assert!(std::panic::catch_unwind(|| input.replace(4, [8, 9])).is_err())
+    }
+
+    #[test]
+    fn count_ones_copy() {
+        const MASK: [u8; 2] = [0xff, 0x0f];
+        const ONES: u32 = MASK.count_ones();
+        const _: () = assert!(MASK.count_ones() == 12 && !MASK.is_zero());
+        assert_eq!(12, ONES);
+        assert_eq!((0, true), ([].count_ones(), [].is_zero()));
+        assert_eq!((0, true), ([0, 0].count_ones(), [0, 0].is_zero()));
+        assert_eq!(
+            (3, false),
+            ([0x80, 0, 0x05].count_ones(), [0x80, 0, 0x05].is_zero())
+        )
+    }
+
+    #[test]
+    fn join_overlapping_copy() {
+        let (a, b, c) = ([1, 2, 3], [3, 4, 5], [5, 6, 7]);
+        let result: [u8; 7] = join_overlapping!(a, b, c);
+        assert_eq!([1, 2, 3, 4, 5, 6, 7], result);
+        assert_eq!(a, join_overlapping!(a));
+        assert_eq!([1, 2, 3, 4, 5], join_overlapping!(a, b,));
+        const TRACK: [u8; 3] = join_overlapping!([1, 2], [2], [2, 3]);
+        assert_eq!([1, 2, 3], TRACK)
+    }
+
+    #[test]
+    fn into_halves_copy() {
+        assert_eq!(([1, 2], [3, 4]), [1, 2, 3, 4].into_halves());
+        assert_eq!(([], [1]), [1].into_halves());
+        const QUARTERS: ([u8; 2], [u8; 2], [u8; 2], [u8; 2]) =
+            [1, 2, 3, 4, 5, 6, 7, 8].into_quarters();
+        assert_eq!(([1, 2], [3, 4], [5, 6], [7, 8]), QUARTERS);
+        assert_eq!(([], [], [], [1, 2, 3]), [1, 2, 3].into_quarters())
+    }
+
+    #[test]
+    fn shift_left_copy() {
+        let (evicted, window) = [1, 2, 3, 4].shift_left([5, 6]);
+        assert_eq!(([1, 2], [3, 4, 5, 6]), (evicted, window));
+        assert_eq!(([], [1, 2]), [1, 2].shift_left([]));
+        assert_eq!(([1, 2], [3, 4]), [1, 2].shift_left([3, 4]));
+        const NEXT: ([u8; 1], [u8; 3]) = [1, 2, 3].shift_left([4]);
+        assert_eq!(([1], [2, 3, 4]), NEXT)
+    }
+
+    #[test]
+    fn max_by_const_copy() {
+        use core::cmp::Ordering;
+
+        const fn by_len(a: &&str, b: &&str) -> Ordering {
+            if a.len() < b.len() {
+                Ordering::Less
+            } else if a.len() > b.len() {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        }
+        const WORDS: [&str; 4] = ["ab", "cde", "f", "ghi"];
+        const LONGEST: Option<&&str> = WORDS.max_by_const(by_len);
+        const SHORTEST: Option<&&str> = WORDS.min_by_const(by_len);
+        assert_eq!((Some(&"ghi"), Some(&"f")), (LONGEST, SHORTEST));
+        let words = ["ab", "cd", "e"];
+        assert_eq!(
+            Some(&"cd"),
+            words.max_by_const(|a, b| a.len().cmp(&b.len()))
+        );
+        assert_eq!(
+            Some(&"ab"),
+            words.min_by_const(|a, b| b.len().cmp(&a.len()))
+        );
+        assert_eq!(None, <[&str; 0]>::default().max_by_const(by_len))
+    }
+
+    #[test]
+    fn run_boundaries_copy() {
+        const fn eq(a: &u8, b: &u8) -> bool {
+            *a == *b
+        }
+        const RUNS: ([usize; 3], usize) = [1, 1, 2, 2, 2, 3].run_boundaries(eq);
+        assert_eq!(([0, 2, 5], 3), RUNS);
+        assert_eq!(([0, 2], 3), [1, 1, 2, 2, 2, 3].run_boundaries(eq));
+        assert_eq!(([0, 0], 1), [7, 7, 7].run_boundaries(eq));
+        assert_eq!(([0, 0], 0), [].run_boundaries(eq));
+        let close = |a: &i8, b: &i8| a.abs_diff(*b) <= 1;
+        assert_eq!(([0, 3], 2), [1, 2, 3, 9, 8].run_boundaries(close))
+    }
+
+    #[test]
+    fn slice_to_array_copy() {
+        let input = [1, 2, 3, 4, 5];
+        assert_eq!(Some([2, 3]), input.slice_to_array(1));
+        assert_eq!(Some([4, 5]), input.slice_to_array(3));
+        assert_eq!(None, input.slice_to_array::<2>(4));
+        assert_eq!(None, input.slice_to_array::<2>(usize::MAX));
+        assert_eq!(None, input.slice_to_array::<6>(0));
+        assert_eq!(Some([]), input.slice_to_array::<0>(5));
+        const FIELD: Option<[u8; 2]> = [9, 8, 7].slice_to_array(1);
+        assert_eq!(Some([8, 7]), FIELD)
+    }
+
+    #[test]
+    fn append_many_copy() {
+        const RESULT: [u8; 4] = [1].append_many([2, 3, 4]);
+        assert_eq!([1, 2, 3, 4], RESULT);
+        let chained: [u8; 3] = [1].push_back(2).push_back(3);
+        assert_eq!(chained, [1].append_many([2, 3]));
+        assert_eq!([1], [1].append_many([]))
+    }
+
+    #[test]
+    fn rposition_copy() {
+        const fn is_zero(e: &u8) -> bool {
+            *e == 0
+        }
+        const LAST: Option<usize> = [0, 1, 0, 2, 0].rposition(is_zero);
+        assert_eq!(Some(4), LAST);
+        assert_eq!(Some(2), [0, 1, 0, 2, 3].rposition(is_zero));
+        assert_eq!(None, [1, 2].rposition(is_zero));
+        assert_eq!(None, [].rposition(is_zero))
+    }
+
+    #[test]
+    fn concat_reversed_copy() {
+        const UNDO: [u8; 5] = [1, 2].concat_reversed([3, 4, 5]);
+        assert_eq!([1, 2, 5, 4, 3], UNDO);
+        let input = [1, 2, 3];
+        assert_eq!(input, input.concat_reversed([]));
+        assert_eq!([3, 2, 1], [].concat_reversed(input))
+    }
+
+    #[test]
+    fn is_sorted_copy() {
+        const fn descending(a: &u8, b: &u8) -> bool {
+            *a >= *b
+        }
+        const TABLE: [u8; 3] = [1, 2, 3];
+        const _: () = assert!(TABLE.is_sorted() && ![1, 3, 2].is_sorted());
+        const _: () =
+            assert!([3, 3, 1].is_sorted_by(descending) && !TABLE.is_sorted_by(descending));
+        assert!([0u8; 0].is_sorted() && [7].is_sorted());
+        assert!(![1.0, f32::NAN].is_sorted());
+        assert!(TABLE.is_sorted_by(|a, b| a < b))
+    }
+
+    #[test]
+    fn flatten_options_copy() {
+        let result = flatten_options([Some(1), None, Some(3)]);
+        assert_eq!(&[1, 3], result.as_slice());
+        assert!(flatten_options::<u8, 2>([None, None]).is_empty());
+        assert_eq!(&[1, 2], flatten_options([Some(1), Some(2)]).as_slice());
+        let dense = flatten_options([None, Some(2), Some(3)]).into_array();
+        assert_eq!(Ok([2, 3]), dense.map_err(|vec| vec.len()))
+    }
+
+    #[test]
+    fn reverse_inplace_copy() {
+        const REVERSED: [u8; 4] = {
+            let mut array = [1, 2, 3, 4];
+            array.reverse_inplace();
+            array
+        };
+        assert_eq!([4, 3, 2, 1], REVERSED);
+        let mut input = [1];
+        input.reverse_inplace();
+        assert_eq!([1], input);
+        let mut empty = [0u8; 0];
+        empty.reverse_inplace()
+    }
+
+    #[test]
+    fn fold_rows_copy() {
+        const fn sum(acc: u8, row: &[u8; 2]) -> u8 {
+            acc + row[0] + row[1]
+        }
+        const SUMS: [u8; 3] = [[1, 2], [3, 4], [5, 6]].fold_rows(0, sum);
+        assert_eq!([3, 7, 11], SUMS);
+        let matrix = [[3.0f32, 4.0], [0.0, 0.0]];
+        let norms = matrix.fold_rows(0.0, |acc, row| {
+            row.iter().fold(acc, |acc, e| acc + e * e).sqrt()
+        });
+        assert_eq!([5.0, 0.0], norms);
+        assert_eq!([0u8; 0], <[[u8; 2]; 0]>::default().fold_rows(0, sum))
+    }
+
+    #[test]
+    fn pad_to_copy() {
+        const PADDED: [u8; 5] = [1, 2, 3].pad_to(0);
+        assert_eq!([1, 2, 3, 0, 0], PADDED);
+        assert_eq!([1, 2], [1, 2].pad_to(0));
+        assert_eq!([7; 3], [].pad_to(7))
+    }
+
+    #[test]
+    fn mask_copy() {
+        const fn is_even(e: &u8) -> bool {
+            e.is_multiple_of(2)
+        }
+        const ACTIVE: [bool; 4] = [1, 2, 3, 4].mask(is_even);
+        assert_eq!([false, true, false, true], ACTIVE);
+        assert_eq!([true; 3], [vec![1], vec![], vec![2]].mask(|_| true));
+        assert_eq!([false; 0], [].mask(is_even))
+    }
+
+    #[test]
+    fn select_copy() {
+        const fn is_even(e: &u8) -> bool {
+            e.is_multiple_of(2)
+        }
         let input = [1, 2, 3, 4];
-        let expected = [1, 2];
-        let result = input.truncate_end::<2>();
-        assert_eq!(expected, result)
+        assert_eq!(
+            [10, 2, 30, 4],
+            input.select([10, 20, 30, 40], input.mask(is_even))
+        );
+        assert_eq!(input, input.select([0; 4], [true; 4]));
+        const BLEND: [u8; 3] = [1, 2, 3].select([4, 5, 6], [false, true, false]);
+        assert_eq!([4, 2, 6], BLEND)
     }
 }